@@ -0,0 +1,191 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+///
+/// Integer answers compare equal by value regardless of the variant holding
+/// them, so `Answer::Unsigned(5) == Answer::Signed(5)`.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(u128),
+    Text(String),
+}
+
+impl Answer {
+    /// The integer value of the answer, if it is one that fits in an `i128`.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(value) => Some(i128::from(*value)),
+            Answer::Signed(value) => Some(i128::from(*value)),
+            Answer::Big(value) => i128::try_from(*value).ok(),
+            Answer::Text(_) => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Answer::Text(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+/// Reads an answer typed in by hand, as the narrowest integer variant it fits
+/// in, falling back to text.
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Ok(value) = s.parse::<u64>() {
+            return Ok(Answer::Unsigned(value));
+        }
+        if let Ok(value) = s.parse::<i64>() {
+            return Ok(Answer::Signed(value));
+        }
+        if let Ok(value) = s.parse::<u128>() {
+            return Ok(Answer::Big(value));
+        }
+        Ok(Answer::Text(s.to_string()))
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(left), Answer::Text(right)) => left == right,
+            (Answer::Big(left), Answer::Big(right)) => left == right,
+            _ => match (self.as_integer(), other.as_integer()) {
+                (Some(left), Some(right)) => left == right,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => value.fmt(f),
+            Answer::Signed(value) => value.fmt(f),
+            Answer::Big(value) => value.fmt(f),
+            Answer::Text(value) => f.pad(value),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($variant:ident($inner:ty): $($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    Answer::$variant(value as $inner)
+                }
+            }
+
+            impl PartialEq<$ty> for Answer {
+                fn eq(&self, other: &$ty) -> bool {
+                    self.as_integer() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(Unsigned(u64): u8, u16, u32, u64, usize);
+impl_from_integer!(Signed(i64): i8, i16, i32, i64, isize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl PartialEq<u128> for Answer {
+    fn eq(&self, other: &u128) -> bool {
+        match self {
+            Answer::Big(value) => value == other,
+            _ => {
+                self.as_integer()
+                    .and_then(|value| u128::try_from(value).ok())
+                    == Some(*other)
+            }
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        self.as_text() == Some(other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_text() == Some(*other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Answer::from(42u32), Answer::Unsigned(42))]
+    #[case(Answer::from(-7i32), Answer::Signed(-7))]
+    #[case(Answer::from(42usize), Answer::Signed(42))]
+    #[case(Answer::from(42u128), Answer::Unsigned(42))]
+    #[case(Answer::from("abc"), Answer::Text(String::from("abc")))]
+    fn answer_equality(#[case] left: Answer, #[case] right: Answer) {
+        assert_eq!(left, right);
+    }
+
+    #[test]
+    fn answer_inequality() {
+        assert_ne!(Answer::Unsigned(1), Answer::Signed(-1));
+        assert_ne!(Answer::Unsigned(1), Answer::from("1"));
+        assert_ne!(Answer::Big(u128::MAX), Answer::Signed(-1));
+    }
+
+    #[test]
+    fn answer_compares_with_primitives() {
+        assert_eq!(Answer::Unsigned(142), 142);
+        assert_eq!(Answer::Big(u128::MAX), u128::MAX);
+        assert_eq!(Answer::from("abc"), "abc");
+    }
+
+    #[rstest]
+    #[case("142", Answer::Unsigned(142))]
+    #[case(" -3\n", Answer::Signed(-3))]
+    #[case("340282366920938463463374607431768211455", Answer::Big(u128::MAX))]
+    #[case("abc", Answer::from("abc"))]
+    fn answer_from_str(#[case] input: &str, #[case] expected: Answer) {
+        let result: Answer = input.parse().unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::Unsigned(142).to_string(), "142");
+        assert_eq!(Answer::Signed(-3).to_string(), "-3");
+        assert_eq!(Answer::Big(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::expected::Answers;
+use crate::{solve, Solver, Unimplemented};
+
+/// Name of the manifest in a day's `examples/` directory. It maps each example
+/// file, by name, to the answers given in the puzzle text:
+///
+/// ```toml
+/// [1]
+/// part1 = 142
+///
+/// [2]
+/// part2 = 281
+/// ```
+pub const MANIFEST: &str = "examples.toml";
+
+/// An example input from a day's `examples/` directory and its known answers.
+#[derive(Debug, PartialEq)]
+pub struct Example {
+    pub name: String,
+    pub path: PathBuf,
+    pub input: String,
+    pub answers: Answers,
+}
+
+impl Example {
+    /// Loads `examples/<name>.txt` from the day's directory `dir`.
+    pub fn load(dir: &Path, name: &str) -> Result<Self, ExampleError> {
+        Self::from_path(&dir.join("examples").join(format!("{}.txt", name)))
+    }
+
+    /// Loads the example file at `path`, looking its answers up in the
+    /// manifest of the same directory.
+    pub fn from_path(path: &Path) -> Result<Self, ExampleError> {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let input = std::fs::read_to_string(path).map_err(|source| ExampleError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let answers = manifest(&path.with_file_name(MANIFEST))?
+            .remove(&name)
+            .ok_or_else(|| ExampleError::Unlisted { name: name.clone() })?;
+
+        Ok(Example {
+            name,
+            path: path.to_path_buf(),
+            input,
+            answers,
+        })
+    }
+}
+
+/// Names of the examples listed in the manifest of the day's directory `dir`.
+pub fn names(dir: &Path) -> Result<Vec<String>, ExampleError> {
+    let manifest = manifest(&dir.join("examples").join(MANIFEST))?;
+    Ok(manifest.into_keys().collect())
+}
+
+fn manifest(path: &Path) -> Result<BTreeMap<String, Answers>, ExampleError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| ExampleError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    toml::from_str(&contents).map_err(|source| ExampleError::Parse {
+        path: path.to_path_buf(),
+        source: Box::new(source),
+    })
+}
+
+/// Solves the example at `path` with `S` and asserts every answer listed for
+/// it in the manifest. Backs the tests generated by [`example_tests!`].
+///
+/// [`example_tests!`]: crate::example_tests
+pub fn check_example<S: Solver>(path: &Path) {
+    let example = Example::from_path(path).unwrap_or_else(|err| panic!("{}", err));
+
+    // Parts without an answer in the manifest, or without a solution yet, are
+    // skipped, so a newly generated day passes until they are filled in.
+    for part in example.answers.parts() {
+        let expected = example.answers.get(part).unwrap();
+        let answer = match solve::<S>(&example.input, part) {
+            Ok(answer) => answer,
+            Err(err) if Unimplemented::caused(&err) => continue,
+            Err(err) => panic!("example {} part {}: {}", example.name, part, err),
+        };
+        assert!(
+            expected.matches(&answer),
+            "example {} part {}: expected {}, got {}",
+            example.name,
+            part,
+            expected,
+            answer
+        );
+    }
+}
+
+/// Generates an rstest case for every `examples/*.txt` file of the calling
+/// crate, checking it against the answers in `examples/examples.toml`.
+///
+/// ```ignore
+/// aoc_core::example_tests!(crate::Solution);
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solver:ty) => {
+        #[cfg(test)]
+        mod examples {
+            use std::path::PathBuf;
+
+            #[rstest::rstest]
+            fn solves_example(#[files("examples/*.txt")] path: PathBuf) {
+                $crate::examples::check_example::<$solver>(&path);
+            }
+        }
+    };
+}
+
+#[derive(Debug, Error)]
+pub enum ExampleError {
+    #[error("failed to read {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("invalid examples manifest {}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+    #[error("example {name:?} is not listed in the examples manifest")]
+    Unlisted { name: String },
+}
@@ -0,0 +1,131 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{Answer, Part};
+
+/// An answer recorded in an `answers.toml` file or an examples manifest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Integer(i64),
+    Text(String),
+}
+
+impl Expected {
+    /// Whether `answer` matches, comparing integers by value and anything else
+    /// by its textual form.
+    pub fn matches(&self, answer: &Answer) -> bool {
+        match self {
+            Expected::Integer(value) => answer == value,
+            Expected::Text(value) => answer.to_string() == *value,
+        }
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Integer(value) => write!(f, "{}", value),
+            Expected::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Known answers for an input, read from an `answers.toml` file such as:
+///
+/// ```toml
+/// part1 = 142
+/// part2 = 281
+/// ```
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+impl Answers {
+    /// Reads the answers at `path`, treating a missing file as no known answers.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(AnswersError::Read {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+
+        toml::from_str(&contents).map_err(|source| AnswersError::Parse {
+            path: path.to_path_buf(),
+            source: Box::new(source),
+        })
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+
+    pub fn parts(&self) -> Vec<Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|part| self.get(*part).is_some())
+            .collect()
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AnswersError {
+    #[error("failed to read answers file {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error("invalid answers file {}: {source}", path.display())]
+    Parse {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_from_toml() {
+        let answers: Answers = toml::from_str("part1 = 142\npart2 = \"abc\"").unwrap();
+        assert_eq!(answers.get(Part::One), Some(&Expected::Integer(142)));
+        assert_eq!(
+            answers.get(Part::Two),
+            Some(&Expected::Text(String::from("abc")))
+        );
+    }
+
+    #[test]
+    fn answers_parts() {
+        let answers: Answers = toml::from_str("part2 = 281").unwrap();
+        assert_eq!(answers.parts(), vec![Part::Two]);
+    }
+
+    #[test]
+    fn expected_matches_answer() {
+        assert!(Expected::Integer(-3).matches(&Answer::Signed(-3)));
+        assert!(Expected::Integer(7).matches(&Answer::Big(7)));
+        assert!(!Expected::Integer(7).matches(&Answer::from("7")));
+        assert!(Expected::Text(u128::MAX.to_string()).matches(&Answer::Big(u128::MAX)));
+        assert!(Expected::Text(String::from("abc")).matches(&Answer::from("abc")));
+    }
+
+    #[test]
+    fn load_missing_answers_file() {
+        let answers = Answers::load(Path::new("does/not/exist.toml")).unwrap();
+        assert_eq!(answers, Answers::default());
+    }
+}
@@ -0,0 +1,191 @@
+//! Differential tests for the inputs written by [`Solver::generate`].
+//!
+//! Every day keeps a `Reference` solver next to its `Solution`: a
+//! deliberately naive one, written straight from the puzzle text and sharing
+//! no code with it. [`generator_tests!`] checks that the two agree.
+//!
+//! [`generator_tests!`]: crate::generator_tests
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::{solve, Part, Solver, Unimplemented};
+
+/// Generates an input of `size` from `seed` with `S`, passes it through
+/// `prepare`, and asserts that `S` answers both parts the same as `R`, the
+/// day's reference solver. Days without a generator, and parts either solver
+/// leaves [`Unimplemented`], are skipped. Backs the tests generated by
+/// [`generator_tests!`].
+///
+/// [`generator_tests!`]: crate::generator_tests
+pub fn check_generated<S: Solver, R: Solver>(
+    seed: u64,
+    size: usize,
+    prepare: fn(String) -> String,
+) {
+    let Some(input) = S::generate(&mut StdRng::seed_from_u64(seed), size) else {
+        return;
+    };
+    let input = prepare(input);
+
+    for part in [Part::One, Part::Two] {
+        let (expected, answer) = match (solve::<R>(&input, part), solve::<S>(&input, part)) {
+            (Err(err), _) if Unimplemented::caused(&err) => continue,
+            (_, Err(err)) if Unimplemented::caused(&err) => continue,
+            (Err(err), _) => panic!("seed {} part {}: reference: {}", seed, part, err),
+            (_, Err(err)) => panic!("seed {} part {}: {}", seed, part, err),
+            (Ok(expected), Ok(answer)) => (expected, answer),
+        };
+        assert_eq!(answer, expected, "seed {} part {}", seed, part);
+    }
+}
+
+/// Generates a proptest checking the calling crate's solver against its
+/// reference on generated inputs, with `cases` random seeds and sizes drawn
+/// from `sizes`. `prepare` can trim an input down to what the reference
+/// solves in reasonable time.
+///
+/// ```ignore
+/// aoc_core::generator_tests!(
+///     crate::Solution,
+///     crate::reference::Reference,
+///     sizes: 1..50usize,
+///     cases: 64,
+/// );
+/// ```
+#[macro_export]
+macro_rules! generator_tests {
+    ($solver:ty, $reference:ty, sizes: $sizes:expr, cases: $cases:expr $(,)?) => {
+        $crate::generator_tests!(
+            $solver,
+            $reference,
+            sizes: $sizes,
+            cases: $cases,
+            prepare: |input| input,
+        );
+    };
+    ($solver:ty, $reference:ty, sizes: $sizes:expr, cases: $cases:expr, prepare: $prepare:expr $(,)?) => {
+        #[cfg(test)]
+        mod generated {
+            use proptest::prelude::*;
+
+            proptest! {
+                #![proptest_config(ProptestConfig::with_cases($cases))]
+
+                #[test]
+                fn agrees_with_reference(seed: u64, size in $sizes) {
+                    $crate::generators::check_generated::<$solver, $reference>(seed, size, $prepare);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use rand::{Rng, RngCore};
+
+    use super::*;
+    use crate::Answer;
+
+    /// Sums the numbers on each line, or just the first one for the
+    /// reference, which is only right when there is a single number.
+    struct Sum;
+    struct First;
+
+    impl Solver for Sum {
+        const DAY: u8 = 1;
+        const DIR: &'static str = "";
+        const INPUT: &'static str = "";
+
+        type Input<'a> = Vec<u64>;
+        type Error = Infallible;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            Ok(input.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok(input.iter().sum::<u64>().into())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Self::part1(input)
+        }
+
+        fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+            let lines: Vec<String> = (0..size)
+                .map(|_| rng.gen_range(1..100u64).to_string())
+                .collect();
+            Some(lines.join("\n"))
+        }
+    }
+
+    impl Solver for First {
+        const DAY: u8 = 1;
+        const DIR: &'static str = "";
+        const INPUT: &'static str = "";
+
+        type Input<'a> = Vec<u64>;
+        type Error = Infallible;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            Sum::parse(input)
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok(input[0].into())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Self::part1(input)
+        }
+    }
+
+    /// A reference that hasn't been written yet.
+    struct Unfinished;
+
+    impl Solver for Unfinished {
+        const DAY: u8 = 1;
+        const DIR: &'static str = "";
+        const INPUT: &'static str = "";
+
+        type Input<'a> = ();
+        type Error = Unimplemented;
+
+        fn parse(_: &str) -> Result<Self::Input<'_>, Self::Error> {
+            Ok(())
+        }
+
+        fn part1(_: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Err(Unimplemented)
+        }
+
+        fn part2(_: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Err(Unimplemented)
+        }
+    }
+
+    #[test]
+    fn check_generated_with_agreeing_reference() {
+        check_generated::<Sum, First>(1, 1, |input| input);
+    }
+
+    #[test]
+    fn check_generated_prepares_input() {
+        check_generated::<Sum, First>(1, 10, |input| input.lines().take(1).collect());
+    }
+
+    #[test]
+    fn check_generated_skips_unimplemented_parts() {
+        check_generated::<Sum, Unfinished>(1, 10, |input| input);
+    }
+
+    #[test]
+    #[should_panic(expected = "seed 1 part 1")]
+    fn check_generated_with_disagreeing_reference() {
+        check_generated::<Sum, First>(1, 10, |input| input);
+    }
+}
@@ -0,0 +1,11 @@
+mod answer;
+pub mod examples;
+pub mod expected;
+pub mod generators;
+mod solver;
+pub mod trace;
+
+pub use answer::Answer;
+pub use solver::{
+    solve, solve_timed, BoxError, Part, Puzzle, PuzzleError, Run, Solved, Solver, Unimplemented,
+};
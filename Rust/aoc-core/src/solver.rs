@@ -0,0 +1,327 @@
+use std::error::Error;
+use std::fmt;
+use std::iter;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::{trace, Answer};
+
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// A puzzle solution for a single day of the calendar.
+///
+/// The input is parsed once with [`Solver::parse`] and the resulting value is
+/// handed to both parts. `Input` may borrow from the raw puzzle input.
+pub trait Solver {
+    const DAY: u8;
+    /// Directory of the day's crate, where `input.txt` and `answers.toml` live.
+    const DIR: &'static str;
+    const INPUT: &'static str;
+
+    type Input<'a>;
+    type Error: Error + Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error>;
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error>;
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error>;
+
+    /// Writes a random, valid input whose size grows with `size`, for stress
+    /// testing. Days without a generator return `None`.
+    fn generate(_rng: &mut dyn RngCore, _size: usize) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(String::from("Invalid part number")),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+pub fn solve<S: Solver>(input: &str, part: Part) -> Result<Answer, S::Error> {
+    let input = S::parse(input)?;
+    match part {
+        Part::One => S::part1(&input),
+        Part::Two => S::part2(&input),
+    }
+}
+
+/// The answer to one part, with the time spent solving it and, when run
+/// inside [`trace::capture`], what the solver traced along the way.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solved {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+    pub trace: Vec<String>,
+}
+
+/// Answers to the requested parts from a single parse of the input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub parts: Vec<Solved>,
+}
+
+/// Parses `input` once and solves each of `parts` against the parsed value.
+pub fn solve_timed<S: Solver>(input: &str, parts: &[Part]) -> Result<Run, PuzzleError> {
+    let start = Instant::now();
+    let input = S::parse(input).map_err(|source| PuzzleError::Parse(source.into()))?;
+    let parse_time = start.elapsed();
+
+    let traced = trace::enabled();
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let solve = || match part {
+                Part::One => S::part1(&input),
+                Part::Two => S::part2(&input),
+            };
+
+            let start = Instant::now();
+            let (answer, trace) = match traced {
+                true => trace::capture(solve),
+                false => (solve(), Vec::new()),
+            };
+            let solve_time = start.elapsed();
+            let answer = answer.map_err(|source| PuzzleError::Solve {
+                part: *part,
+                source: source.into(),
+            })?;
+
+            Ok(Solved {
+                part: *part,
+                answer,
+                solve_time,
+                trace,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Run { parse_time, parts })
+}
+
+/// Failure to parse the input or to solve one of its parts.
+#[derive(Debug)]
+pub enum PuzzleError {
+    Parse(BoxError),
+    Solve { part: Part, source: BoxError },
+}
+
+impl fmt::Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleError::Parse(source) => write!(f, "{}", source),
+            PuzzleError::Solve { part, source } => write!(f, "part {}: {}", part, source),
+        }
+    }
+}
+
+impl Error for PuzzleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PuzzleError::Parse(source) | PuzzleError::Solve { source, .. } => Some(source.as_ref()),
+        }
+    }
+}
+
+/// What a part that hasn't been written yet returns, like the ones of a newly
+/// generated day. The runner reports it as a failed part, while the test
+/// harnesses skip the part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unimplemented;
+
+impl Unimplemented {
+    /// Whether `error`, or any error that caused it, is [`Unimplemented`].
+    pub fn caused(error: &(dyn Error + 'static)) -> bool {
+        iter::successors(Some(error), |&error| error.source()).any(|error| error.is::<Self>())
+    }
+}
+
+impl fmt::Display for Unimplemented {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not implemented yet")
+    }
+}
+
+impl Error for Unimplemented {}
+
+/// Type-erased handle to a [`Solver`], used to dispatch days at runtime.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub day: u8,
+    pub dir: &'static str,
+    pub input: &'static str,
+    solve: fn(&str, &[Part]) -> Result<Run, PuzzleError>,
+    generate: fn(&mut dyn RngCore, usize) -> Option<String>,
+}
+
+impl Puzzle {
+    pub const fn new<S: Solver>() -> Self {
+        Puzzle {
+            day: S::DAY,
+            dir: S::DIR,
+            input: S::INPUT,
+            solve: solve_timed::<S>,
+            generate: S::generate,
+        }
+    }
+
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Result<Run, PuzzleError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Generates an input with [`Solver::generate`], the same one for the same
+    /// `seed` and `size`.
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        (self.generate)(&mut StdRng::seed_from_u64(seed), size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use super::*;
+
+    struct Sum;
+
+    impl Solver for Sum {
+        const DAY: u8 = 0;
+        const DIR: &'static str = "";
+        const INPUT: &'static str = "2\n3\n4";
+
+        type Input<'a> = Vec<u32>;
+        type Error = ParseIntError;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+            input.lines().map(|line| line.parse()).collect()
+        }
+
+        fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            crate::trace!("adding {} numbers", input.len());
+            Ok(input.iter().sum::<u32>().into())
+        }
+
+        fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+            Ok(input.iter().product::<u32>().into())
+        }
+
+        fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+            let lines: Vec<String> = (0..size)
+                .map(|_| (rng.next_u32() % 10).to_string())
+                .collect();
+            Some(lines.join("\n"))
+        }
+    }
+
+    #[test]
+    fn solve_dispatches_on_part() {
+        assert_eq!(solve::<Sum>("2\n3\n4", Part::One).unwrap(), 9);
+        assert_eq!(solve::<Sum>("2\n3\n4", Part::Two).unwrap(), 24);
+    }
+
+    #[test]
+    fn puzzle_generates_reproducible_input() {
+        let puzzle = Puzzle::new::<Sum>();
+        let input = puzzle.generate(7, 5).unwrap();
+        assert_eq!(input.lines().count(), 5);
+        assert_eq!(puzzle.generate(7, 5), Some(input.clone()));
+        assert_ne!(puzzle.generate(8, 5), Some(input.clone()));
+        assert!(puzzle.solve(&input, &[Part::One]).is_ok());
+    }
+
+    #[test]
+    fn solve_with_invalid_input() {
+        assert!(solve::<Sum>("2\nx", Part::One).is_err());
+    }
+
+    #[test]
+    fn puzzle_solves_embedded_input() {
+        let puzzle = Puzzle::new::<Sum>();
+        let run = puzzle.solve(puzzle.input, &[Part::One, Part::Two]).unwrap();
+        assert_eq!(puzzle.day, 0);
+        assert_eq!(run.parts.len(), 2);
+        assert_eq!(
+            (run.parts[0].part, &run.parts[0].answer),
+            (Part::One, &Answer::from(9u32))
+        );
+        assert_eq!(
+            (run.parts[1].part, &run.parts[1].answer),
+            (Part::Two, &Answer::from(24u32))
+        );
+    }
+
+    #[test]
+    fn puzzle_reports_failing_step() {
+        let puzzle = Puzzle::new::<Sum>();
+        let error = puzzle.solve("2\nx", &[Part::One]).unwrap_err();
+        assert!(matches!(error, PuzzleError::Parse(_)));
+        assert_eq!(error.to_string(), "invalid digit found in string");
+    }
+
+    #[test]
+    fn puzzle_keeps_trace_of_each_part() {
+        let puzzle = Puzzle::new::<Sum>();
+        let (run, _) = trace::capture(|| puzzle.solve("2\n3", &[Part::One, Part::Two]));
+        let run = run.unwrap();
+        assert_eq!(run.parts[0].trace, ["adding 2 numbers"]);
+        assert!(run.parts[1].trace.is_empty());
+
+        let run = puzzle.solve("2\n3", &[Part::One]).unwrap();
+        assert!(run.parts[0].trace.is_empty());
+    }
+
+    #[test]
+    fn unimplemented_found_among_causes() {
+        let error = PuzzleError::Solve {
+            part: Part::One,
+            source: Box::new(Unimplemented),
+        };
+        assert!(Unimplemented::caused(&error));
+        assert_eq!(error.to_string(), "part 1: not implemented yet");
+
+        let error = PuzzleError::Parse("x".parse::<u32>().unwrap_err().into());
+        assert!(!Unimplemented::caused(&error));
+    }
+
+    #[test]
+    fn part_from_str() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+    }
+}
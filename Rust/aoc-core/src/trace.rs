@@ -0,0 +1,103 @@
+//! Explanations of how a solver reached its answer, written by the days with
+//! [`trace!`](crate::trace!) and shown by the runner's `--explain`.
+//!
+//! Lines are only kept inside [`capture`], on the thread that called it.
+//! Anywhere else `trace!` checks a thread-local flag and skips formatting its
+//! arguments, so solvers can trace inside hot loops.
+
+use std::cell::RefCell;
+
+thread_local! {
+    static LINES: RefCell<Option<Vec<String>>> = const { RefCell::new(None) };
+}
+
+/// Formats a line into the trace when one is being captured.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit(format!($($arg)*));
+        }
+    };
+}
+
+/// Whether this thread is capturing a trace.
+pub fn enabled() -> bool {
+    LINES.with(|lines| lines.borrow().is_some())
+}
+
+#[doc(hidden)]
+pub fn emit(line: String) {
+    LINES.with(|lines| {
+        if let Some(lines) = lines.borrow_mut().as_mut() {
+            lines.push(line);
+        }
+    });
+}
+
+/// Runs `f` with tracing enabled and returns what it traced. An enclosing
+/// capture doesn't see the lines of a nested one.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    // Restores the enclosing capture even if `f` panics.
+    struct Restore(Option<Vec<String>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            LINES.set(self.0.take());
+        }
+    }
+
+    let restore = Restore(LINES.replace(Some(Vec::new())));
+    let value = f();
+    let lines = LINES.take().unwrap_or_default();
+    drop(restore);
+
+    (value, lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_without_capture_is_dropped() {
+        trace!("not {}", "kept");
+        assert!(!enabled());
+        assert_eq!(capture(|| ()).1, Vec::<String>::new());
+    }
+
+    #[test]
+    fn capture_collects_lines() {
+        let (value, lines) = capture(|| {
+            trace!("first {}", 1);
+            trace!("second");
+            3
+        });
+        assert_eq!(value, 3);
+        assert_eq!(lines, ["first 1", "second"]);
+        assert!(!enabled());
+    }
+
+    #[test]
+    fn nested_capture_keeps_its_own_lines() {
+        let ((_, inner), outer) = capture(|| {
+            trace!("outer");
+            let inner = capture(|| trace!("inner"));
+            trace!("outer again");
+            inner
+        });
+        assert_eq!(inner, ["inner"]);
+        assert_eq!(outer, ["outer", "outer again"]);
+    }
+
+    #[test]
+    fn trace_skips_formatting_when_disabled() {
+        let mut formatted = false;
+        let mut argument = || {
+            formatted = true;
+            0
+        };
+        trace!("{}", argument());
+        assert!(!formatted);
+    }
+}
@@ -0,0 +1,305 @@
+//! A rectangular grid of cells, the shape of many puzzle inputs.
+
+use std::fmt;
+use std::ops::{Index, IndexMut, Range};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+/// A cell's `(row, column)`, counting from the top left corner.
+pub type Position = (usize, usize);
+
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be as long as the first.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for row in rows {
+            let expected = *width.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(GridError::Ragged {
+                    row: height,
+                    length: row.len(),
+                    width: expected,
+                });
+            }
+            cells.extend(row);
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        let index = self.index(position);
+        Some(&mut self.cells[index])
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        (row < self.height).then(|| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).filter_map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        (0..self.height).filter_map(move |row| self.get((row, col)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
+        self.region(0..self.height, 0..self.width)
+    }
+
+    /// The cells within `rows` and `cols`, cut down to the ones inside the
+    /// grid, in row-major order.
+    pub fn region(
+        &self,
+        rows: Range<usize>,
+        cols: Range<usize>,
+    ) -> impl Iterator<Item = (Position, &T)> + '_ {
+        let rows = rows.start..rows.end.min(self.height);
+        let cols = cols.start..cols.end.min(self.width);
+
+        rows.flat_map(move |row| {
+            cols.clone()
+                .map(move |col| ((row, col), &self.cells[self.index((row, col))]))
+        })
+    }
+
+    /// Positions of the cells above, left, right and below `position`.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    /// Positions of the cells surrounding `position`, diagonals included, in
+    /// row-major order.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .into_iter()
+            .filter_map(move |offset| self.offset(position, offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index(&self, (row, col): Position) -> usize {
+        row * self.width + col
+    }
+
+    fn offset(&self, (row, col): Position, (rows, cols): (isize, isize)) -> Option<Position> {
+        let position = (row.checked_add_signed(rows)?, col.checked_add_signed(cols)?);
+        self.contains(position).then_some(position)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+/// Reads one cell per character, one row per line.
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_rows(s.lines().map(|line| line.chars().collect()))
+    }
+}
+
+/// Writes the rows one per line, the inverse of parsing a `Grid<char>`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum GridError {
+    #[error("line {} has {length} cells but the first one has {width}", .row + 1)]
+    Ragged {
+        row: usize,
+        length: usize,
+        width: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    const GRID: &str = "123\n456";
+
+    fn grid() -> Grid<char> {
+        GRID.parse().unwrap()
+    }
+
+    #[test]
+    fn parse_and_display_round_trip() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), GRID);
+    }
+
+    #[rstest]
+    #[case("", 0, 0)]
+    #[case("\n\n", 0, 2)]
+    fn parse_empty(#[case] input: &str, #[case] width: usize, #[case] height: usize) {
+        let grid: Grid<char> = input.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (width, height));
+        assert_eq!(grid.iter().count(), 0);
+    }
+
+    #[test]
+    fn parse_ragged() {
+        let error = "123\n45\n6".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(
+            error,
+            GridError::Ragged {
+                row: 1,
+                length: 2,
+                width: 3
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 2 has 2 cells but the first one has 3"
+        );
+    }
+
+    #[rstest]
+    #[case((0, 0), Some('1'))]
+    #[case((1, 2), Some('6'))]
+    #[case((2, 0), None)]
+    #[case((0, 3), None)]
+    fn get(#[case] position: Position, #[case] expected: Option<char>) {
+        assert_eq!(grid().get(position).copied(), expected);
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut grid = grid();
+        grid[(1, 1)] = '.';
+        assert_eq!(grid.to_string(), "123\n4.6");
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(rows, ["123", "456"]);
+        assert_eq!(columns, ["14", "25", "36"]);
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[rstest]
+    #[case(0..1, 0..3, "123")]
+    #[case(0..5, 1..2, "25")]
+    #[case(1..9, 2..9, "6")]
+    #[case(3..5, 0..3, "")]
+    fn region(#[case] rows: Range<usize>, #[case] cols: Range<usize>, #[case] expected: &str) {
+        let cells: String = grid().region(rows, cols).map(|(_, cell)| cell).collect();
+        assert_eq!(cells, expected);
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(0, 1), (1, 0)])]
+    #[case((1, 1), vec![(0, 1), (1, 0), (1, 2)])]
+    fn neighbours4(#[case] position: Position, #[case] expected: Vec<Position>) {
+        assert_eq!(grid().neighbours4(position).collect::<Vec<_>>(), expected);
+    }
+
+    #[rstest]
+    #[case((0, 0), vec![(0, 1), (1, 0), (1, 1)])]
+    #[case((1, 1), vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)])]
+    fn neighbours8(#[case] position: Position, #[case] expected: Vec<Position>) {
+        assert_eq!(grid().neighbours8(position).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn map() {
+        let digits = grid().map(|cell| cell.to_digit(10).unwrap());
+        assert_eq!(digits[(1, 2)], 6);
+        assert_eq!(digits.to_string(), GRID);
+    }
+}
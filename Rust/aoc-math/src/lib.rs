@@ -0,0 +1,198 @@
+//! Number theory that keeps coming up in puzzles about cycles: greatest
+//! common divisors, least common multiples and the Chinese Remainder Theorem.
+//!
+//! Everything that could overflow is checked and returns `None` instead.
+
+/// Greatest common divisor, with Stein's binary algorithm. `gcd(0, b)` is `b`.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+
+    // Common factors of two are put back at the end.
+    let shift = (a | b).trailing_zeros();
+    a >>= a.trailing_zeros();
+    loop {
+        b >>= b.trailing_zeros();
+        if a > b {
+            std::mem::swap(&mut a, &mut b);
+        }
+        b -= a;
+        if b == 0 {
+            return a << shift;
+        }
+    }
+}
+
+/// Least common multiple, or `None` if it doesn't fit in a `u64`. It is 0
+/// when either value is.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Least common multiple of all `values`, 1 if there are none.
+pub fn lcm_all(values: impl IntoIterator<Item = u64>) -> Option<u64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g`, where `g` is the
+/// non-negative greatest common divisor of `a` and `b`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` such that `a * x ≡ 1 (mod modulus)`, if `a` and
+/// `modulus` are coprime.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(i128::from(a), i128::from(modulus));
+    if g != 1 {
+        return None;
+    }
+    i64::try_from(x.rem_euclid(i128::from(modulus))).ok()
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)`, whose moduli
+/// don't need to be coprime.
+///
+/// Returns the smallest non-negative solution and the least common multiple
+/// of the moduli, which every other solution differs from it by a multiple
+/// of. Returns `None` when the congruences contradict each other, a modulus
+/// isn't positive or the result doesn't fit in an `i64`.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(residue, modulus), (other, other_modulus)| {
+            merge(residue, modulus, other, other_modulus)
+        })
+}
+
+/// Combines `x ≡ a (mod m)` and `x ≡ b (mod n)` into a single congruence.
+fn merge(a: i64, m: i64, b: i64, n: i64) -> Option<(i64, i64)> {
+    if n <= 0 {
+        return None;
+    }
+    let (a, m, b, n) = (i128::from(a), i128::from(m), i128::from(b), i128::from(n));
+
+    // x = a + m * k, so m * k ≡ b - a (mod n), which only has solutions when
+    // the gcd of m and n divides b - a.
+    let (g, inverse, _) = extended_gcd(m, n);
+    let difference = b - a;
+    if difference % g != 0 {
+        return None;
+    }
+    let step = n / g;
+    let k = (difference / g % step * inverse).rem_euclid(step);
+
+    let modulus = m.checked_mul(step)?;
+    let residue = (a + m * k).rem_euclid(modulus);
+    Some((i64::try_from(residue).ok()?, i64::try_from(modulus).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(0, 0, 0)]
+    #[case(0, 7, 7)]
+    #[case(12, 0, 12)]
+    #[case(12, 18, 6)]
+    #[case(17, 5, 1)]
+    #[case(1, u64::MAX, 1)]
+    #[case(1 << 40, 3 << 20, 1 << 20)]
+    fn gcd_of(#[case] a: u64, #[case] b: u64, #[case] expected: u64) {
+        assert_eq!(gcd(a, b), expected);
+        assert_eq!(gcd(b, a), expected);
+    }
+
+    #[rstest]
+    #[case(4, 6, Some(12))]
+    #[case(0, 6, Some(0))]
+    #[case(u64::MAX, u64::MAX, Some(u64::MAX))]
+    #[case(u64::MAX, 2, None)]
+    fn lcm_of(#[case] a: u64, #[case] b: u64, #[case] expected: Option<u64>) {
+        assert_eq!(lcm(a, b), expected);
+    }
+
+    #[rstest]
+    #[case(vec![], Some(1))]
+    #[case(vec![2, 3, 4], Some(12))]
+    #[case(vec![u64::MAX, u64::MAX - 1], None)]
+    fn lcm_all_of(#[case] values: Vec<u64>, #[case] expected: Option<u64>) {
+        assert_eq!(lcm_all(values), expected);
+    }
+
+    #[rstest]
+    #[case(3, 7, Some(5))]
+    #[case(-3, 7, Some(2))]
+    #[case(4, 8, None)]
+    #[case(1, 1, Some(0))]
+    #[case(3, 0, None)]
+    fn mod_inverse_of(#[case] a: i64, #[case] modulus: i64, #[case] expected: Option<i64>) {
+        assert_eq!(mod_inverse(a, modulus), expected);
+    }
+
+    #[rstest]
+    #[case(vec![], Some((0, 1)))]
+    #[case(vec![(2, 3), (3, 5), (2, 7)], Some((23, 105)))]
+    #[case(vec![(1, 4), (3, 6)], Some((9, 12)))]
+    #[case(vec![(1, 4), (2, 6)], None)]
+    #[case(vec![(-1, 5)], Some((4, 5)))]
+    #[case(vec![(0, 0)], None)]
+    fn crt_of(#[case] congruences: Vec<(i64, i64)>, #[case] expected: Option<(i64, i64)>) {
+        assert_eq!(crt(congruences), expected);
+    }
+
+    proptest! {
+        #[test]
+        fn extended_gcd_satisfies_bezout(a in -1_000_000_i128..1_000_000, b in -1_000_000_i128..1_000_000) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(a * x + b * y, g);
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs() as u64, b.unsigned_abs() as u64));
+        }
+
+        #[test]
+        fn crt_solves_every_congruence(
+            congruences in prop::collection::vec((-1000_i64..1000, 1_i64..50), 0..5)
+        ) {
+            let Some((x, modulus)) = crt(congruences.clone()) else {
+                // No solution at all, which brute force confirms.
+                let lcm = congruences.iter().try_fold(1, |l, &(_, m)| lcm(l, m as u64)).unwrap();
+                let unsolvable = (0..lcm as i64).all(|x| {
+                    congruences.iter().any(|&(r, m)| x.rem_euclid(m) != r.rem_euclid(m))
+                });
+                prop_assert!(unsolvable);
+                return Ok(());
+            };
+            prop_assert!((0..modulus).contains(&x));
+            for (residue, m) in congruences {
+                prop_assert_eq!(x.rem_euclid(m), residue.rem_euclid(m));
+                prop_assert_eq!(modulus % m, 0);
+            }
+        }
+    }
+}
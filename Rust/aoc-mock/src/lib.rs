@@ -0,0 +1,340 @@
+//! A local stand-in for the parts of the Advent of Code website used by the
+//! `aoc` client: downloading puzzle inputs and submitting answers.
+
+use std::collections::{BTreeMap, HashSet};
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use aoc_core::expected::{Answers, AnswersError, Expected};
+use aoc_core::Part;
+use thiserror::Error;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+pub const YEAR: u16 = 2023;
+
+/// Fixtures bundled with this crate, laid out like the workspace's day crates.
+pub const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+
+/// The input and answers served for a day.
+#[derive(Debug, Default)]
+pub struct Fixture {
+    pub input: String,
+    pub answers: Answers,
+}
+
+#[derive(Debug)]
+pub struct Config {
+    /// Value of the `session` cookie the client has to send.
+    pub session: String,
+    /// How long wrong answers lock out further submissions.
+    pub cooldown: Duration,
+    pub days: BTreeMap<u8, Fixture>,
+}
+
+impl Config {
+    /// Reads `day-NN/input.txt` and `day-NN/answers.toml` from every day
+    /// directory in `dir`.
+    pub fn load(dir: &Path, session: impl Into<String>) -> Result<Self, FixtureError> {
+        let entries = std::fs::read_dir(dir).map_err(|source| FixtureError::Read {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+
+        let mut days = BTreeMap::new();
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some(day) = name.strip_prefix("day-").and_then(|day| day.parse().ok()) else {
+                continue;
+            };
+
+            let path = entry.path().join("input.txt");
+            let input = std::fs::read_to_string(&path)
+                .map_err(|source| FixtureError::Read { path, source })?;
+            let answers = Answers::load(&entry.path().join("answers.toml"))?;
+            days.insert(day, Fixture { input, answers });
+        }
+
+        Ok(Config {
+            session: session.into(),
+            cooldown: Duration::from_secs(60),
+            days,
+        })
+    }
+}
+
+/// A running server, stopped when dropped.
+pub struct MockServer {
+    server: Arc<Server>,
+    addr: SocketAddr,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// Starts serving `config` on `addr`; use port 0 to pick a free port.
+    pub fn start(addr: &str, config: Config) -> Result<Self, FixtureError> {
+        let server = Server::http(addr).map_err(|source| FixtureError::Bind {
+            addr: addr.to_string(),
+            source,
+        })?;
+        let server = Arc::new(server);
+        let addr = server
+            .server_addr()
+            .to_ip()
+            .expect("listening on a TCP socket");
+
+        let thread = {
+            let server = Arc::clone(&server);
+            thread::spawn(move || {
+                let mut state = State::new(config);
+                for request in server.incoming_requests() {
+                    state.handle(request);
+                }
+            })
+        };
+
+        Ok(MockServer {
+            server,
+            addr,
+            thread: Some(thread),
+        })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Blocks until the server thread stops.
+    pub fn join(mut self) {
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+struct State {
+    config: Config,
+    solved: HashSet<(u8, Part)>,
+    locked_until: Option<Instant>,
+}
+
+impl State {
+    fn new(config: Config) -> Self {
+        State {
+            config,
+            solved: HashSet::new(),
+            locked_until: None,
+        }
+    }
+
+    fn handle(&mut self, mut request: Request) {
+        let mut body = String::new();
+        let response = match request.as_reader().read_to_string(&mut body) {
+            Ok(_) => self.respond(&request, &body),
+            Err(_) => text(400, "Bad request"),
+        };
+        let _ = request.respond(response);
+    }
+
+    fn respond(&mut self, request: &Request, body: &str) -> Response<io::Cursor<Vec<u8>>> {
+        let segments: Vec<&str> = request.url().trim_matches('/').split('/').collect();
+        let (day, action) = match segments.as_slice() {
+            [year, "day", day, action] if *year == YEAR.to_string() => (day.parse().ok(), *action),
+            _ => return text(404, "404 Not Found"),
+        };
+        let Some((day, fixture)) = day.and_then(|day: u8| Some((day, self.config.days.get(&day)?)))
+        else {
+            return text(404, "404 Not Found");
+        };
+
+        if !self.authenticated(request) {
+            return text(
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            );
+        }
+
+        match (request.method(), action) {
+            (Method::Get, "input") => text(200, &fixture.input),
+            (Method::Post, "answer") => {
+                let form = parse_form(body);
+                let level = form
+                    .get("level")
+                    .and_then(|level| level.parse::<Part>().ok());
+                match (level, form.get("answer")) {
+                    (Some(part), Some(answer)) => {
+                        let message = self.submit(day, part, answer.trim());
+                        page(day, &message)
+                    }
+                    _ => text(400, "Bad request"),
+                }
+            }
+            _ => text(404, "404 Not Found"),
+        }
+    }
+
+    fn authenticated(&self, request: &Request) -> bool {
+        let expected = format!("session={}", self.config.session);
+        request
+            .headers()
+            .iter()
+            .filter(|header| header.field.equiv("Cookie"))
+            .flat_map(|header| header.value.as_str().split(';'))
+            .any(|cookie| cookie.trim() == expected)
+    }
+
+    fn submit(&mut self, day: u8, part: Part, answer: &str) -> String {
+        let back = format!(
+            " <a href=\"/{}/day/{}\">[Return to Day {}]</a>",
+            YEAR, day, day
+        );
+
+        if let Some(locked_until) = self.locked_until {
+            let now = Instant::now();
+            if now < locked_until {
+                let left = (locked_until - now).as_secs() + 1;
+                return format!(
+                    "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have {}s left to wait.{}",
+                    left, back
+                );
+            }
+        }
+
+        let unlocked = part == Part::One || self.solved.contains(&(day, Part::One));
+        if self.solved.contains(&(day, part)) || !unlocked {
+            return format!(
+                "You don't seem to be solving the right level.  Did you already complete it?{}",
+                back
+            );
+        }
+
+        let expected = self.config.days[&day].answers.get(part);
+        let hint = match expected {
+            Some(expected) if matches(expected, answer) => {
+                self.solved.insert((day, part));
+                return format!(
+                    "That's the right answer!  You are one gold star closer to restoring snow operations.{}",
+                    back
+                );
+            }
+            Some(Expected::Integer(expected)) => match answer.parse::<i128>() {
+                Ok(value) if value > i128::from(*expected) => "; your answer is too high",
+                Ok(_) => "; your answer is too low",
+                Err(_) => ".",
+            },
+            _ => ".",
+        };
+
+        self.locked_until = Some(Instant::now() + self.config.cooldown);
+        format!(
+            "That's not the right answer{}  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.{}",
+            hint, back
+        )
+    }
+}
+
+fn matches(expected: &Expected, answer: &str) -> bool {
+    match expected {
+        Expected::Integer(expected) => answer.parse::<i64>() == Ok(*expected),
+        Expected::Text(expected) => answer == expected,
+    }
+}
+
+/// Decodes an `application/x-www-form-urlencoded` body.
+fn parse_form(body: &str) -> BTreeMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (decode(key), decode(value)))
+        .collect()
+}
+
+fn decode(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut chars = value.bytes();
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [chars.next(), chars.next()];
+                let decoded = match hex {
+                    [Some(high), Some(low)] => std::str::from_utf8(&[high, low])
+                        .ok()
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok()),
+                    _ => None,
+                };
+                bytes.push(decoded.unwrap_or(b'%'));
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn text(status: u16, body: &str) -> Response<io::Cursor<Vec<u8>>> {
+    let body = if body.ends_with('\n') {
+        body.to_string()
+    } else {
+        format!("{}\n", body)
+    };
+    Response::from_string(body)
+        .with_status_code(status)
+        .with_header(content_type("text/plain"))
+}
+
+fn page(day: u8, message: &str) -> Response<io::Cursor<Vec<u8>>> {
+    let html = format!(
+        "<!DOCTYPE html>\n<html lang=\"en-us\">\n<head>\n<title>Day {day} - Advent of Code {YEAR}</title>\n</head>\n<body>\n<main>\n<article><p>{message}</p></article>\n</main>\n</body>\n</html>\n"
+    );
+    Response::from_string(html).with_header(content_type("text/html; charset=utf-8"))
+}
+
+fn content_type(value: &str) -> Header {
+    Header::from_bytes("Content-Type", value).expect("valid header")
+}
+
+#[derive(Debug, Error)]
+pub enum FixtureError {
+    #[error("failed to read fixture {}: {source}", path.display())]
+    Read { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    Answers(#[from] AnswersError),
+    #[error("failed to listen on {addr}: {source}")]
+    Bind {
+        addr: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_form_values() {
+        let form = parse_form("level=2&answer=a+b%21");
+        assert_eq!(form.get("level").map(String::as_str), Some("2"));
+        assert_eq!(form.get("answer").map(String::as_str), Some("a b!"));
+    }
+
+    #[test]
+    fn load_bundled_fixtures() {
+        let config = Config::load(Path::new(FIXTURES), "secret").unwrap();
+        assert_eq!(config.days.keys().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(
+            config.days[&1].answers.get(Part::One),
+            Some(&Expected::Integer(142))
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc_mock::{Config, MockServer, FIXTURES};
+use clap::Parser;
+
+/// Serve puzzle inputs and answer pages like the Advent of Code website
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[arg(long, default_value = "127.0.0.1:3000", help = "Address to listen on")]
+    addr: String,
+    #[arg(
+        long,
+        value_name = "DIR",
+        default_value = FIXTURES,
+        help = "Directory with day-NN/input.txt and day-NN/answers.toml fixtures"
+    )]
+    fixtures: PathBuf,
+    #[arg(
+        long,
+        default_value = "mock-session",
+        help = "Session cookie clients have to send"
+    )]
+    session: String,
+    #[arg(
+        long,
+        value_name = "SECONDS",
+        default_value_t = 60,
+        help = "Lockout after a wrong answer"
+    )]
+    cooldown: u64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let server = Config::load(&cli.fixtures, cli.session)
+        .map(|config| Config {
+            cooldown: Duration::from_secs(cli.cooldown),
+            ..config
+        })
+        .and_then(|config| MockServer::start(&cli.addr, config));
+
+    match server {
+        Ok(server) => {
+            println!("serving {} on {}", cli.fixtures.display(), server.url());
+            server.join();
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
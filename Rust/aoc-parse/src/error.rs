@@ -0,0 +1,214 @@
+use std::fmt;
+
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+use nom::Offset;
+
+/// The error type of the parsers in this crate: nom's own, plus the innermost
+/// [`context`](nom::error::context) the parser failed in, which names what
+/// was expected better than the [`ErrorKind`] does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error<'a> {
+    pub input: &'a str,
+    pub code: ErrorKind,
+    pub context: Option<&'static str>,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for Error<'a> {
+    fn from_error_kind(input: &'a str, code: ErrorKind) -> Self {
+        Error {
+            input,
+            code,
+            context: None,
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> ContextError<&'a str> for Error<'a> {
+    fn add_context(input: &'a str, context: &'static str, mut other: Self) -> Self {
+        if other.context.is_none() {
+            other.context = Some(context);
+            other.input = input;
+        }
+        other
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for Error<'a> {
+    fn from_external_error(input: &'a str, code: ErrorKind, _: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, code)
+    }
+}
+
+impl<'a> From<nom::error::Error<&'a str>> for Error<'a> {
+    fn from(error: nom::error::Error<&'a str>) -> Self {
+        nom::error::ParseError::from_error_kind(error.input, error.code)
+    }
+}
+
+/// A parsing failure pointing at the offending line and column of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error for `remaining`, which must be a slice of `input`.
+    pub fn at(input: &str, remaining: &str, message: impl Into<String>) -> Self {
+        let offset = input.offset(remaining).min(input.len());
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let column = match consumed.rfind('\n') {
+            Some(index) => consumed[index + 1..].chars().count() + 1,
+            None => consumed.chars().count() + 1,
+        };
+
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn from_nom<'a>(input: &'a str, error: impl Into<Error<'a>>) -> Self {
+        let error = error.into();
+        let message = match (error.context, error.code) {
+            (_, ErrorKind::Eof) if error.input.is_empty() => {
+                String::from("unexpected end of input")
+            }
+            (_, ErrorKind::Eof) => format!("unexpected input{}", near(error.input)),
+            (Some(context), _) => format!("expected {}{}", context, near(error.input)),
+            (None, ErrorKind::MapRes | ErrorKind::MapOpt | ErrorKind::Verify) => {
+                format!("invalid value{}", near(error.input))
+            }
+            (None, code) => format!("expected {}{}", code.description(), near(error.input)),
+        };
+        Self::at(input, error.input, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` over the whole `input`, allowing only trailing whitespace,
+/// such as the final newline, to be left over.
+pub fn finish<'a, T, E: Into<Error<'a>>>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> nom::IResult<&'a str, T, E>,
+) -> Result<T, ParseError> {
+    match parser(input) {
+        Ok((remaining, value)) => {
+            let remaining = remaining.trim_start();
+            if remaining.is_empty() {
+                Ok(value)
+            } else {
+                let message = format!("unexpected input{}", near(remaining));
+                Err(ParseError::at(input, remaining, message))
+            }
+        }
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            Err(ParseError::from_nom(input, error))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            "unexpected end of input",
+        )),
+    }
+}
+
+fn near(remaining: &str) -> String {
+    let snippet: String = remaining
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .take(20)
+        .collect();
+    if snippet.is_empty() {
+        String::new()
+    } else {
+        format!(" near {:?}", snippet)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::bytes::complete::{tag, take};
+    use nom::character::complete::{self, line_ending};
+    use nom::combinator::all_consuming;
+    use nom::error::context;
+    use nom::multi::separated_list1;
+    use nom::sequence::preceded;
+    use nom::IResult;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn parse_numbers(input: &str) -> IResult<&str, Vec<u32>> {
+        separated_list1(line_ending, preceded(tag("n="), complete::u32))(input)
+    }
+
+    #[test]
+    fn finish_with_valid_input() {
+        let result = finish("n=1\nn=2\n", parse_numbers);
+        assert_eq!(result, Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn finish_reports_line_and_column() {
+        let error = finish("n=1\nn=2\nn=x", parse_numbers).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "unexpected input near \"n=x\"");
+    }
+
+    #[test]
+    fn from_nom_reports_line_and_column() {
+        let input = "n=1\nn=a";
+        let error = preceded(tag("n=1\nn="), complete::u32)(input).unwrap_err();
+        let error: nom::error::Error<&str> = match error {
+            nom::Err::Error(error) => error,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            ParseError::from_nom(input, error).to_string(),
+            "line 2, column 3: expected Digit near \"a\""
+        );
+    }
+
+    #[rstest]
+    #[case("n=", "line 1, column 3: unexpected end of input")]
+    #[case("n=12", "line 1, column 4: unexpected input near \"2\"")]
+    fn from_nom_reports_eof_only_at_the_end(#[case] input: &str, #[case] expected: &str) {
+        let parser = preceded(tag("n="), take(1usize));
+        let error = finish::<_, Error>(input, all_consuming(parser)).unwrap_err();
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[test]
+    fn from_nom_reports_innermost_context() {
+        let parser = context(
+            "numbers",
+            context("number", preceded(tag("n="), complete::u32)),
+        );
+        let error = finish::<_, Error>("n=x", parser).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected number near \"n=x\""
+        );
+    }
+}
@@ -0,0 +1,206 @@
+//! nom combinators for the shapes puzzle inputs keep coming in, and the error
+//! reporting around them.
+//!
+//! The combinators name what they expect with nom's
+//! [`context`](nom::error::context), which [`finish`] puts in its message:
+//!
+//! ```
+//! use aoc_parse::{finish, labelled, numbers};
+//!
+//! let times: Vec<u64> = finish("Time:  7  15  30", labelled("Time:", numbers)).unwrap();
+//! assert_eq!(times, [7, 15, 30]);
+//!
+//! let error = finish("Tmie:  7  15  30", labelled("Time:", numbers::<u64>)).unwrap_err();
+//! assert_eq!(error.to_string(), "line 1, column 1: expected Time: near \"Tmie:  7  15  30\"");
+//! ```
+
+use std::str::FromStr;
+
+use aoc_grid::Grid;
+use nom::bytes::complete::{tag, take_till1};
+use nom::character::complete::{char, digit1, line_ending, space0, space1};
+use nom::combinator::{map_res, value};
+use nom::error::context;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, separated_pair, tuple};
+
+mod error;
+
+pub use error::{finish, Error, ParseError};
+
+/// What the parsers in this crate return.
+pub type IResult<'a, T> = nom::IResult<&'a str, T, Error<'a>>;
+
+/// An unsigned integer of any type that parses from its digits.
+pub fn number<'a, T: FromStr>(input: &'a str) -> IResult<'a, T> {
+    context("number", map_res(digit1, str::parse))(input)
+}
+
+/// Unsigned integers separated by spaces, such as `83 86  6 31`.
+pub fn numbers<'a, T: FromStr>(input: &'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(space1, number)(input)
+}
+
+/// `label` and any spaces after it, then `parser`.
+pub fn labelled<'a, T>(
+    label: &'static str,
+    parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    context(label, preceded(pair(tag(label), space0), parser))
+}
+
+/// One or more lines matching `parser`. The newline after the last one is
+/// left alone, for [`finish`] to skip or for a [`blank_line`] to start with.
+pub fn lines<'a, T>(
+    parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(line_ending, parser)
+}
+
+/// The end of a line followed by an empty one, between two sections.
+pub fn blank_line(input: &str) -> IResult<'_, ()> {
+    value((), pair(line_ending, line_ending))(input)
+}
+
+/// One or more sections matching `parser`, separated by blank lines.
+pub fn sections<'a, T>(
+    parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    separated_list1(blank_line, parser)
+}
+
+/// A `header:` line, such as `seed-to-soil map:`, followed by `body`.
+pub fn section<'a, T>(
+    header: &'static str,
+    body: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    context(
+        header,
+        preceded(tuple((tag(header), char(':'), line_ending)), body),
+    )
+}
+
+/// A `key = (left, right)` line.
+pub fn key_pair<'a, K, L, R>(
+    key: impl FnMut(&'a str) -> IResult<'a, K>,
+    left: impl FnMut(&'a str) -> IResult<'a, L>,
+    right: impl FnMut(&'a str) -> IResult<'a, R>,
+) -> impl FnMut(&'a str) -> IResult<'a, (K, (L, R))> {
+    separated_pair(
+        key,
+        tag(" = "),
+        delimited(char('('), separated_pair(left, tag(", "), right), char(')')),
+    )
+}
+
+/// A grid of characters, one row per line, up to a blank line or the end of
+/// the input. Rows must all be the same length.
+pub fn grid(input: &str) -> IResult<'_, Grid<char>> {
+    let row = take_till1(|c| c == '\n' || c == '\r');
+    let rows = lines(row);
+
+    context(
+        "rows of the same length",
+        map_res(rows, |rows: Vec<&str>| {
+            Grid::from_rows(rows.into_iter().map(|row| row.chars().collect()))
+        }),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::character::complete::alpha1;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("7  15   30", vec![7, 15, 30])]
+    #[case("7 15 | 30", vec![7, 15])]
+    #[case("18446744073709551615", vec![u64::MAX])]
+    fn numbers_with_valid_input(#[case] input: &str, #[case] expected: Vec<u64>) {
+        let (_, result) = numbers::<u64>(input).unwrap();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("x", "line 1, column 1: expected number near \"x\"")]
+    #[case(
+        "18446744073709551616",
+        "line 1, column 1: expected number near \"18446744073709551616\""
+    )]
+    fn numbers_with_invalid_input(#[case] input: &str, #[case] expected: &str) {
+        let error = finish(input, numbers::<u64>).unwrap_err();
+        assert_eq!(error.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case("seeds: 79 14", vec![79, 14])]
+    #[case("seeds:79", vec![79])]
+    fn labelled_with_valid_input(#[case] input: &str, #[case] expected: Vec<u32>) {
+        assert_eq!(finish(input, labelled("seeds:", numbers)), Ok(expected));
+    }
+
+    #[test]
+    fn labelled_with_missing_number() {
+        let error = finish("seeds: x", labelled("seeds:", numbers::<u32>)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: expected number near \"x\""
+        );
+    }
+
+    #[test]
+    fn lines_leave_final_newline() {
+        let (remaining, result) = lines(numbers::<u32>)("1 2\n3\n").unwrap();
+        assert_eq!((remaining, result), ("\n", vec![vec![1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn sections_with_headers() {
+        let input = "a:\n1 2\n3\n\nb:\n4\n";
+        let parser = pair(
+            section("a", lines(numbers::<u32>)),
+            preceded(blank_line, section("b", lines(numbers))),
+        );
+        let result = finish(input, parser).unwrap();
+        assert_eq!(result, (vec![vec![1, 2], vec![3]], vec![vec![4]]));
+    }
+
+    #[test]
+    fn section_with_wrong_header() {
+        let input = "a:\n1\n\nc:\n2";
+        let parser = pair(
+            section("a", numbers::<u32>),
+            preceded(blank_line, section("b", numbers::<u32>)),
+        );
+        let error = finish(input, parser).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 1: expected b near \"c:\""
+        );
+    }
+
+    #[test]
+    fn sections_of_grids() {
+        let input = "#.\n.#\n\n###\n";
+        let grids = finish(input, sections(grid)).unwrap();
+        let grids: Vec<String> = grids.iter().map(Grid::to_string).collect();
+        assert_eq!(grids, ["#.\n.#", "###"]);
+    }
+
+    #[test]
+    fn grid_with_ragged_rows() {
+        let error = finish("#.\n.#.", grid).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 1: expected rows of the same length near \"#.\""
+        );
+    }
+
+    #[test]
+    fn key_pair_of_nodes() {
+        let result = finish("AAA = (BBB, CCC)", key_pair(alpha1, alpha1, alpha1));
+        assert_eq!(result, Ok(("AAA", ("BBB", "CCC"))));
+    }
+}
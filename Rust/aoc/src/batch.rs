@@ -0,0 +1,234 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Part, PuzzleError};
+
+use crate::check::Status;
+use crate::input::InputError;
+use crate::report::{Record, Table};
+
+/// Extension of the file with the known answers to the input of the same name.
+pub const ANSWERS_EXTENSION: &str = "answers";
+
+/// An input in a batch directory, named after its file without the extension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl Entry {
+    pub fn answers_path(&self) -> PathBuf {
+        self.path
+            .with_file_name(format!("{}.{}", self.name, ANSWERS_EXTENSION))
+    }
+}
+
+/// Every input file in `dir`, sorted by name. Answers files and hidden
+/// files are skipped.
+pub fn discover(dir: &Path) -> Result<Vec<Entry>, InputError> {
+    let error = |source| InputError::Directory {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut entries = Vec::new();
+    for entry in std::fs::read_dir(dir).map_err(error)? {
+        let path = entry.map_err(error)?.path();
+        let Some(name) = path.file_stem().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        let is_answers = path
+            .extension()
+            .is_some_and(|extension| extension == ANSWERS_EXTENSION);
+        if path.is_file() && !is_answers && !name.starts_with('.') {
+            entries.push(Entry {
+                name: name.to_string(),
+                path,
+            });
+        }
+    }
+
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(entries)
+}
+
+/// The result of solving one input of the batch.
+#[derive(Debug)]
+pub struct Outcome {
+    pub entry: Entry,
+    pub result: Result<Record, PuzzleError>,
+}
+
+impl Outcome {
+    /// Whether the input failed to solve or got an answer other than the
+    /// expected one.
+    pub fn mismatched(&self) -> bool {
+        match &self.result {
+            Ok(record) => record
+                .parts
+                .iter()
+                .any(|part| matches!(part.check, Some(Status::Fail(_)))),
+            Err(_) => true,
+        }
+    }
+}
+
+/// A matrix with a row per input and a column per part, followed by the
+/// details of every mismatch.
+pub fn render(outcomes: &[Outcome], parts: &[Part]) -> String {
+    let mut header = vec!["Input"];
+    header.extend(parts.iter().map(|part| match part {
+        Part::One => "Part 1",
+        Part::Two => "Part 2",
+    }));
+
+    let mut table = Table::new(header);
+    let mut mismatches = Vec::new();
+
+    for outcome in outcomes {
+        let name = &outcome.entry.name;
+        let mut row = vec![name.clone()];
+        match &outcome.result {
+            Ok(record) => {
+                for part in record.parts.iter() {
+                    match &part.check {
+                        Some(Status::Fail(expected)) => {
+                            row.push(format!("{} FAIL", part.answer));
+                            mismatches.push(format!(
+                                "{} part {}: {}, expected {}",
+                                name, part.part, part.answer, expected
+                            ));
+                        }
+                        Some(Status::Pass) => row.push(format!("{} PASS", part.answer)),
+                        _ => row.push(part.answer.to_string()),
+                    }
+                }
+            }
+            Err(error) => {
+                row.extend(parts.iter().map(|_| String::from("ERROR")));
+                mismatches.push(format!("{}: {}", name, error));
+            }
+        }
+        table.push(row);
+    }
+
+    let mut output = table.to_string();
+    if !mismatches.is_empty() {
+        output.push_str("\nMismatches:\n");
+        for mismatch in mismatches {
+            output.push_str(&format!("  {}\n", mismatch));
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use aoc_core::expected::Expected;
+    use aoc_core::Answer;
+
+    use super::*;
+    use crate::report::PartRecord;
+
+    fn entry(name: &str) -> Entry {
+        Entry {
+            name: name.to_string(),
+            path: PathBuf::from(format!("inputs/{}.txt", name)),
+        }
+    }
+
+    fn record(parts: Vec<PartRecord>) -> Record {
+        Record {
+            day: 1,
+            input_path: String::new(),
+            parse_time: Duration::ZERO,
+            parts,
+            error: None,
+        }
+    }
+
+    fn part(part: Part, answer: u32, check: Status) -> PartRecord {
+        PartRecord {
+            part,
+            answer: Answer::from(answer),
+            solve_time: Duration::ZERO,
+            check: Some(check),
+            expected: None,
+            trace: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn answers_next_to_input() {
+        assert_eq!(
+            entry("alice").answers_path(),
+            PathBuf::from("inputs/alice.answers")
+        );
+    }
+
+    #[test]
+    fn discover_skips_answers_and_hidden_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        for file in ["bob.txt", "alice", "alice.answers", ".hidden"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+
+        let entries = discover(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<_> = entries.iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(entries[0].answers_path(), dir.join("alice.answers"));
+    }
+
+    #[test]
+    fn discover_missing_directory() {
+        let error = discover(Path::new("does/not/exist")).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("failed to read input directory does/not/exist: "));
+    }
+
+    #[test]
+    fn render_matrix_with_mismatches() {
+        let outcomes = [
+            Outcome {
+                entry: entry("alice"),
+                result: Ok(record(vec![
+                    part(Part::One, 142, Status::Pass),
+                    part(Part::Two, 281, Status::Missing),
+                ])),
+            },
+            Outcome {
+                entry: entry("bob"),
+                result: Ok(record(vec![
+                    part(Part::One, 7, Status::Fail(Expected::Integer(8))),
+                    part(Part::Two, 9, Status::Pass),
+                ])),
+            },
+            Outcome {
+                entry: entry("carol"),
+                result: Err(PuzzleError::Parse("no digits".into())),
+            },
+        ];
+        assert_eq!(
+            outcomes.iter().map(Outcome::mismatched).collect::<Vec<_>>(),
+            [false, true, true]
+        );
+        assert_eq!(
+            render(&outcomes, &[Part::One, Part::Two]),
+            "Input  Part 1    Part 2\n\
+             alice  142 PASS  281\n\
+             bob    7 FAIL    9 PASS\n\
+             carol  ERROR     ERROR\n\
+             \n\
+             Mismatches:\n  \
+             bob part 1: 7, expected 8\n  \
+             carol: no digits\n"
+        );
+    }
+}
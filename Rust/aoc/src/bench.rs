@@ -0,0 +1,392 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc_core::{Part, Puzzle, PuzzleError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::report::Table;
+
+/// Summary statistics over a set of timings, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut samples: Vec<u64> = samples
+            .iter()
+            .map(|sample| sample.as_nanos().try_into().unwrap_or(u64::MAX))
+            .collect();
+        samples.sort_unstable();
+
+        let count = samples.len().max(1) as f64;
+        let mean = samples.iter().map(|sample| *sample as f64).sum::<f64>() / count;
+        let variance = samples
+            .iter()
+            .map(|sample| (*sample as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let median = match samples.len() {
+            0 => 0,
+            len if len % 2 == 0 => (samples[len / 2 - 1] + samples[len / 2]) / 2,
+            len => samples[len / 2],
+        };
+
+        Stats {
+            min_ns: samples.first().copied().unwrap_or_default(),
+            median_ns: median,
+            mean_ns: mean.round() as u64,
+            stddev_ns: variance.sqrt().round() as u64,
+        }
+    }
+}
+
+/// Parse statistics for a day and solve statistics for each measured part.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: Vec<PartMeasurement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartMeasurement {
+    pub part: u8,
+    pub solve: Stats,
+}
+
+/// Runs `parts` of `puzzle` `iterations` times after a single warm-up run.
+pub fn measure(
+    puzzle: &Puzzle,
+    input: &str,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Measurement, PuzzleError> {
+    puzzle.solve(input, parts)?;
+
+    let mut parse = Vec::with_capacity(iterations);
+    let mut solve = vec![Vec::with_capacity(iterations); parts.len()];
+    for _ in 0..iterations {
+        let run = puzzle.solve(input, parts)?;
+        parse.push(run.parse_time);
+        for (samples, solved) in solve.iter_mut().zip(run.parts) {
+            samples.push(solved.solve_time);
+        }
+    }
+
+    Ok(Measurement {
+        day: puzzle.day,
+        iterations,
+        parse: Stats::new(&parse),
+        parts: parts
+            .iter()
+            .zip(solve)
+            .map(|(part, samples)| PartMeasurement {
+                part: (*part).into(),
+                solve: Stats::new(&samples),
+            })
+            .collect(),
+    })
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let contents = std::fs::read_to_string(path).map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        serde_json::from_str(&contents).map_err(|source| BaselineError::Format {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Like [`Baseline::load`], but a missing file is an empty baseline.
+    pub fn load_or_default(path: &Path) -> Result<Self, BaselineError> {
+        match Self::load(path) {
+            Err(BaselineError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            result => result,
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), BaselineError> {
+        let contents = serde_json::to_string_pretty(self).expect("baseline serializes");
+        std::fs::write(path, contents + "\n").map_err(|source| BaselineError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn find(&self, day: u8) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|measurement| measurement.day == day)
+    }
+
+    /// Replaces what `measurement` re-measured, the day's parsing and its
+    /// parts, and keeps everything else.
+    pub fn merge(&mut self, measurement: Measurement) {
+        let Some(previous) = self
+            .measurements
+            .iter_mut()
+            .find(|previous| previous.day == measurement.day)
+        else {
+            self.measurements.push(measurement);
+            self.measurements.sort_by_key(|measurement| measurement.day);
+            return;
+        };
+
+        previous.iterations = measurement.iterations;
+        previous.parse = measurement.parse;
+        for part in measurement.parts {
+            match previous.parts.iter_mut().find(|p| p.part == part.part) {
+                Some(existing) => *existing = part,
+                None => previous.parts.push(part),
+            }
+        }
+        previous.parts.sort_by_key(|part| part.part);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    Regressed(f64),
+    Improved(f64),
+    Unchanged(f64),
+}
+
+impl Change {
+    /// Compares the medians of two runs, ignoring differences within `threshold` percent.
+    pub fn new(baseline: &Stats, current: &Stats, threshold: f64) -> Self {
+        let percent = match baseline.median_ns {
+            0 => 0.0,
+            old => (current.median_ns as f64 - old as f64) / old as f64 * 100.0,
+        };
+
+        if percent > threshold {
+            Change::Regressed(percent)
+        } else if percent < -threshold {
+            Change::Improved(percent)
+        } else {
+            Change::Unchanged(percent)
+        }
+    }
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Change::Regressed(percent) => write!(f, "{:+.1}% REGRESSION", percent),
+            Change::Improved(percent) => write!(f, "{:+.1}% improved", percent),
+            Change::Unchanged(percent) => write!(f, "{:+.1}%", percent),
+        }
+    }
+}
+
+pub fn render(
+    measurements: &[Measurement],
+    baseline: Option<&Baseline>,
+    threshold: f64,
+) -> (String, usize) {
+    let mut header = vec!["Day", "Part", "Phase", "Min", "Median", "Mean", "StdDev"];
+    if baseline.is_some() {
+        header.push("Change");
+    }
+
+    let mut table = Table::new(header);
+    let mut regressions = 0;
+    for measurement in measurements {
+        let previous = baseline.and_then(|baseline| baseline.find(measurement.day));
+
+        // The input is parsed once per run, so parsing gets a single row for the day.
+        let mut rows = vec![(
+            String::new(),
+            "parse",
+            &measurement.parse,
+            previous.map(|previous| &previous.parse),
+        )];
+        for part in measurement.parts.iter() {
+            let previous = previous
+                .and_then(|previous| previous.parts.iter().find(|p| p.part == part.part))
+                .map(|previous| &previous.solve);
+            rows.push((part.part.to_string(), "solve", &part.solve, previous));
+        }
+
+        for (part, phase, stats, previous) in rows {
+            let mut row = vec![measurement.day.to_string(), part, phase.to_string()];
+            row.extend(
+                [
+                    stats.min_ns,
+                    stats.median_ns,
+                    stats.mean_ns,
+                    stats.stddev_ns,
+                ]
+                .iter()
+                .map(|ns| format!("{:.2?}", Duration::from_nanos(*ns))),
+            );
+
+            if baseline.is_some() {
+                let change = match previous {
+                    Some(previous) => {
+                        let change = Change::new(previous, stats, threshold);
+                        if let Change::Regressed(_) = change {
+                            regressions += 1;
+                        }
+                        change.to_string()
+                    }
+                    None => String::from("no baseline"),
+                };
+                row.push(change);
+            }
+            table.push(row);
+        }
+    }
+
+    (table.to_string(), regressions)
+}
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("failed to access baseline file {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("invalid baseline file {}: {source}", path.display())]
+    Format {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median_ns: u64) -> Stats {
+        Stats {
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].into_iter().map(Duration::from_nanos).collect();
+        let result = Stats::new(&samples);
+        assert_eq!(
+            result,
+            Stats {
+                min_ns: 1,
+                median_ns: 2,
+                mean_ns: 3,
+                stddev_ns: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn stats_from_odd_samples() {
+        let samples: Vec<Duration> = [10, 30, 20].into_iter().map(Duration::from_nanos).collect();
+        let result = Stats::new(&samples);
+        assert_eq!(result.median_ns, 20);
+        assert_eq!(result.mean_ns, 20);
+        assert_eq!(result.stddev_ns, 8);
+    }
+
+    #[test]
+    fn change_against_baseline() {
+        assert_eq!(
+            Change::new(&stats(100), &stats(120), 10.0),
+            Change::Regressed(20.0)
+        );
+        assert_eq!(
+            Change::new(&stats(100), &stats(50), 10.0),
+            Change::Improved(-50.0)
+        );
+        assert_eq!(
+            Change::new(&stats(100), &stats(105), 10.0),
+            Change::Unchanged(5.0)
+        );
+    }
+
+    fn measurement(day: u8, parse: u64, parts: &[(u8, u64)]) -> Measurement {
+        Measurement {
+            day,
+            iterations: 1,
+            parse: stats(parse),
+            parts: parts
+                .iter()
+                .map(|&(part, solve)| PartMeasurement {
+                    part,
+                    solve: stats(solve),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn merge_replaces_only_remeasured_entries() {
+        let mut baseline = Baseline {
+            measurements: vec![
+                measurement(1, 10, &[(1, 100), (2, 200)]),
+                measurement(7, 70, &[(1, 700), (2, 800)]),
+            ],
+        };
+        baseline.merge(measurement(7, 75, &[(2, 850)]));
+        baseline.merge(measurement(3, 30, &[(1, 300)]));
+
+        assert_eq!(
+            baseline.measurements,
+            [
+                measurement(1, 10, &[(1, 100), (2, 200)]),
+                measurement(3, 30, &[(1, 300)]),
+                measurement(7, 75, &[(1, 700), (2, 850)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_or_default_without_a_file() {
+        let path = std::env::temp_dir().join("aoc-bench-missing-baseline.json");
+        let baseline = Baseline::load_or_default(&path).unwrap();
+        assert!(baseline.measurements.is_empty());
+    }
+
+    #[test]
+    fn render_flags_regressions() {
+        let measurement = Measurement {
+            day: 1,
+            iterations: 1,
+            parse: stats(100),
+            parts: vec![PartMeasurement {
+                part: 1,
+                solve: stats(1000),
+            }],
+        };
+        let baseline = Baseline {
+            measurements: vec![Measurement {
+                parts: vec![PartMeasurement {
+                    part: 1,
+                    solve: stats(500),
+                }],
+                ..measurement.clone()
+            }],
+        };
+        let (_, regressions) = render(&[measurement], Some(&baseline), 10.0);
+        assert_eq!(regressions, 1);
+    }
+}
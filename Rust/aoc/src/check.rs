@@ -0,0 +1,60 @@
+use std::fmt;
+
+use aoc_core::expected::{Answers, Expected};
+use aoc_core::{Answer, Part};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail(Expected),
+    Missing,
+}
+
+impl Status {
+    pub fn new(answers: &Answers, part: Part, answer: &Answer) -> Self {
+        match answers.get(part) {
+            Some(expected) if expected.matches(answer) => Status::Pass,
+            Some(expected) => Status::Fail(expected.clone()),
+            None => Status::Missing,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail(_) => "FAIL",
+            Status::Missing => "MISSING",
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Fail(expected) => write!(f, "{} (expected {})", self.label(), expected),
+            _ => write!(f, "{}", self.label()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_against_answers() {
+        let answers: Answers = toml::from_str("part1 = 142").unwrap();
+        assert_eq!(
+            Status::new(&answers, Part::One, &Answer::from(142u32)),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::new(&answers, Part::One, &Answer::from(143u32)),
+            Status::Fail(Expected::Integer(142))
+        );
+        assert_eq!(
+            Status::new(&answers, Part::Two, &Answer::from(1u32)),
+            Status::Missing
+        );
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::time::Duration;
+
+use aoc_core::{Answer, Part};
+use thiserror::Error;
+
+pub const YEAR: u16 = 2023;
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(
+    "github.com/DFilipeS/advent-of-code-2023 aoc/",
+    env!("CARGO_PKG_VERSION")
+);
+
+/// HTTP client for the Advent of Code website, or anything that serves the
+/// same pages, such as the `aoc-mock` server.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(USER_AGENT)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn fetch_input(&self, day: u8) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(&self.url(day, "input"))
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(ClientError::from_ureq)?;
+
+        read_body(response)
+    }
+
+    pub fn submit(&self, day: u8, part: Part, answer: &Answer) -> Result<Verdict, ClientError> {
+        let level = u8::from(part).to_string();
+        let answer = answer.to_string();
+        let response = self
+            .agent
+            .post(&self.url(day, "answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", &answer)])
+            .map_err(ClientError::from_ureq)?;
+
+        Ok(Verdict::from_page(&read_body(response)?))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, YEAR, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn read_body(response: ureq::Response) -> Result<String, ClientError> {
+    let mut body = String::new();
+    response
+        .into_reader()
+        .read_to_string(&mut body)
+        .map_err(|source| ClientError::Read { source })?;
+    Ok(body)
+}
+
+/// The outcome of submitting an answer, as reported by the answer page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    RateLimited { wait: Option<String> },
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    pub fn from_page(page: &str) -> Self {
+        let message = article(page);
+
+        if message.contains("That's the right answer") {
+            Verdict::Correct
+        } else if message.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if message.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if message.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if message.contains("You gave an answer too recently") {
+            let wait = message
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Verdict::RateLimited { wait }
+        } else if message.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown(message)
+        }
+    }
+
+    pub fn is_correct(&self) -> bool {
+        *self == Verdict::Correct
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "incorrect, too high"),
+            Verdict::TooLow => write!(f, "incorrect, too low"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, {} left to wait", wait)
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "already solved or part locked"),
+            Verdict::Unknown(message) => write!(f, "unrecognised response: {}", message),
+        }
+    }
+}
+
+/// Text of the page's `<article>` element with the markup stripped.
+fn article(page: &str) -> String {
+    let content = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(content, _)| content);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in content.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    #[error("request failed with status {status}: {body}")]
+    Status { status: u16, body: String },
+    #[error("request failed: {source}")]
+    Transport { source: Box<ureq::Transport> },
+    #[error("failed to read response: {source}")]
+    Read { source: io::Error },
+}
+
+impl ClientError {
+    fn from_ureq(error: ureq::Error) -> Self {
+        match error {
+            ureq::Error::Status(status, response) => ClientError::Status {
+                status,
+                body: response
+                    .into_string()
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string(),
+            },
+            ureq::Error::Transport(source) => ClientError::Transport {
+                source: Box::new(source),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(
+        "<article><p>That's the right answer!  You are one gold star closer.</p></article>",
+        Verdict::Correct
+    )]
+    #[case(
+        "<article><p>That's not the right answer.  If you're stuck...</p></article>",
+        Verdict::Incorrect
+    )]
+    #[case(
+        "<article><p>That's not the right answer; your answer is too high.</p></article>",
+        Verdict::TooHigh
+    )]
+    #[case(
+        "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        Verdict::TooLow
+    )]
+    #[case(
+        "<article><p>You gave an answer too recently; you have to wait.  You have 42s left to wait.</p></article>",
+        Verdict::RateLimited { wait: Some(String::from("42s")) }
+    )]
+    #[case(
+        "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/1\">[Return to Day 1]</a></p></article>",
+        Verdict::AlreadySolved
+    )]
+    #[case(
+        "<main><article class=\"day-desc\"><p>Something <em>else</em></p></article></main>",
+        Verdict::Unknown(String::from("Something else"))
+    )]
+    fn verdict_from_page(#[case] page: &str, #[case] expected: Verdict) {
+        assert_eq!(Verdict::from_page(page), expected);
+    }
+}
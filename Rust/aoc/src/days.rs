@@ -0,0 +1,33 @@
+use aoc_core::Puzzle;
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle::new::<day_01::Solution>(),
+    Puzzle::new::<day_02::Solution>(),
+    Puzzle::new::<day_03::Solution>(),
+    Puzzle::new::<day_04::Solution>(),
+    Puzzle::new::<day_05::Solution>(),
+    Puzzle::new::<day_06::Solution>(),
+    Puzzle::new::<day_07::Solution>(),
+    Puzzle::new::<day_08::Solution>(),
+];
+
+pub fn find(day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|puzzle| puzzle.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzles_are_sorted_and_unique() {
+        let days: Vec<u8> = PUZZLES.iter().map(|puzzle| puzzle.day).collect();
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn find_unknown_day() {
+        assert!(find(8).is_some());
+        assert!(find(26).is_none());
+    }
+}
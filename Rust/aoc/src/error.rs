@@ -0,0 +1,47 @@
+use std::io;
+use std::path::PathBuf;
+
+use aoc_core::{Answer, PuzzleError};
+use thiserror::Error;
+
+use crate::bench::BaselineError;
+use crate::client::{ClientError, Verdict};
+use crate::history::{Conflict, HistoryError};
+use crate::input::InputError;
+use crate::vault::VaultError;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error(transparent)]
+    Input(#[from] InputError),
+    #[error(transparent)]
+    Baseline(#[from] BaselineError),
+    #[error("day {day}: {source}")]
+    Solve { day: u8, source: PuzzleError },
+    #[error("{errors} day(s) failed to solve")]
+    SolveFailed { errors: usize },
+    #[error("{failed} answer(s) did not match the expected value")]
+    CheckFailed { failed: usize },
+    #[error("{inputs} input(s) failed or did not match their answers")]
+    Mismatched { inputs: usize },
+    #[error("{regressions} measurement(s) regressed against the baseline")]
+    Regressed { regressions: usize },
+    #[error("day {day} has no input generator")]
+    NoGenerator { day: u8 },
+    #[error(transparent)]
+    Client(#[from] ClientError),
+    #[error("{} already exists, pass --force to overwrite it", path.display())]
+    InputExists { path: PathBuf },
+    #[error("failed to write {}: {source}", path.display())]
+    Write { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    History(#[from] HistoryError),
+    #[error(transparent)]
+    Vault(#[from] VaultError),
+    #[error("refusing to submit {answer}, it is {conflict} (pass --force to submit anyway)")]
+    Guarded { answer: Answer, conflict: Conflict },
+    #[error("answer was not accepted: {verdict}")]
+    Rejected { verdict: Verdict },
+    #[error("terminal error: {0}")]
+    Terminal(io::Error),
+}
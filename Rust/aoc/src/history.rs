@@ -0,0 +1,236 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_core::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::client::Verdict;
+
+pub const FILE_NAME: &str = "submissions.toml";
+
+/// Answers submitted for a day, with what the website made of them.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct History {
+    #[serde(default, rename = "submission")]
+    pub submissions: Vec<Submission>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+}
+
+impl Outcome {
+    /// Verdicts that say nothing about the answer, like being rate limited,
+    /// are not worth remembering.
+    pub fn from_verdict(verdict: &Verdict) -> Option<Self> {
+        match verdict {
+            Verdict::Correct => Some(Outcome::Correct),
+            Verdict::Incorrect => Some(Outcome::Incorrect),
+            Verdict::TooHigh => Some(Outcome::TooHigh),
+            Verdict::TooLow => Some(Outcome::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// Why submitting an answer is pointless according to the history.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conflict {
+    Solved { answer: String },
+    Rejected { outcome: Outcome },
+    AboveTooHigh { bound: String },
+    BelowTooLow { bound: String },
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Conflict::Solved { answer } => write!(f, "already solved with {}", answer),
+            Conflict::Rejected { outcome } => {
+                write!(f, "already submitted and found {}", outcome)
+            }
+            Conflict::AboveTooHigh { bound } => {
+                write!(f, "not below {}, which was too high", bound)
+            }
+            Conflict::BelowTooLow { bound } => write!(f, "not above {}, which was too low", bound),
+        }
+    }
+}
+
+impl History {
+    /// Loads the history at `path`, or an empty one if the file doesn't exist.
+    pub fn load(path: &Path) -> Result<Self, HistoryError> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|source| HistoryError::Format {
+                path: path.to_path_buf(),
+                source: Box::new(source),
+            }),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(source) => Err(HistoryError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), HistoryError> {
+        let contents = toml::to_string(self).expect("history serializes");
+        std::fs::write(path, contents).map_err(|source| HistoryError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    /// Adds a submission, returning whether its verdict was worth keeping.
+    pub fn record(&mut self, part: Part, answer: &Answer, verdict: &Verdict) -> bool {
+        let Some(outcome) = Outcome::from_verdict(verdict) else {
+            return false;
+        };
+        self.submissions.push(Submission {
+            part: part.into(),
+            answer: answer.to_string(),
+            outcome,
+        });
+        true
+    }
+
+    /// Checks `answer` against earlier submissions for the same part.
+    pub fn check(&self, part: Part, answer: &Answer) -> Option<Conflict> {
+        let submissions: Vec<(&Submission, Answer)> = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.part == u8::from(part))
+            .map(|submission| (submission, submission.answer.parse().unwrap()))
+            .collect();
+
+        if let Some((submission, _)) = submissions
+            .iter()
+            .find(|(submission, _)| submission.outcome == Outcome::Correct)
+        {
+            return Some(Conflict::Solved {
+                answer: submission.answer.clone(),
+            });
+        }
+
+        if let Some((submission, _)) = submissions.iter().find(|(_, other)| other == answer) {
+            return Some(Conflict::Rejected {
+                outcome: submission.outcome,
+            });
+        }
+
+        let value = answer.as_integer()?;
+        let bound = |outcome| {
+            submissions
+                .iter()
+                .filter(move |(submission, _)| submission.outcome == outcome)
+                .filter_map(|(_, other)| other.as_integer())
+        };
+        if let Some(bound) = bound(Outcome::TooHigh)
+            .filter(|&bound| value >= bound)
+            .min()
+        {
+            return Some(Conflict::AboveTooHigh {
+                bound: bound.to_string(),
+            });
+        }
+        if let Some(bound) = bound(Outcome::TooLow).filter(|&bound| value <= bound).max() {
+            return Some(Conflict::BelowTooLow {
+                bound: bound.to_string(),
+            });
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum HistoryError {
+    #[error("failed to access submissions file {}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    #[error("invalid submissions file {}: {source}", path.display())]
+    Format {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    fn history() -> History {
+        let mut history = History::default();
+        history.record(Part::One, &Answer::from(500), &Verdict::TooHigh);
+        history.record(Part::One, &Answer::from(100), &Verdict::TooLow);
+        history.record(Part::One, &Answer::from(300), &Verdict::Incorrect);
+        history.record(
+            Part::One,
+            &Answer::from(0),
+            &Verdict::RateLimited { wait: None },
+        );
+        history.record(Part::One, &Answer::from(450), &Verdict::TooHigh);
+        history.record(Part::Two, &Answer::from("abc"), &Verdict::Correct);
+        history
+    }
+
+    #[rstest]
+    #[case(Part::One, Answer::from(200), None)]
+    #[case(Part::One, Answer::from(0), Some(Conflict::BelowTooLow { bound: String::from("100") }))]
+    #[case(Part::One, Answer::from(100), Some(Conflict::Rejected { outcome: Outcome::TooLow }))]
+    #[case(Part::One, Answer::from(300), Some(Conflict::Rejected { outcome: Outcome::Incorrect }))]
+    #[case(Part::One, Answer::from(470), Some(Conflict::AboveTooHigh { bound: String::from("450") }))]
+    #[case(Part::One, Answer::from("xyz"), None)]
+    #[case(Part::Two, Answer::from(1), Some(Conflict::Solved { answer: String::from("abc") }))]
+    fn check_against_history(
+        #[case] part: Part,
+        #[case] answer: Answer,
+        #[case] expected: Option<Conflict>,
+    ) {
+        assert_eq!(history().check(part, &answer), expected);
+    }
+
+    #[test]
+    fn history_round_trips() {
+        let history = history();
+        assert_eq!(history.submissions.len(), 5);
+
+        let contents = toml::to_string(&history).unwrap();
+        assert!(contents
+            .starts_with("[[submission]]\npart = 1\nanswer = \"500\"\noutcome = \"too-high\"\n"));
+        assert_eq!(toml::from_str::<History>(&contents).unwrap(), history);
+    }
+
+    #[test]
+    fn load_missing_history() {
+        let history = History::load(Path::new("does/not/exist.toml")).unwrap();
+        assert_eq!(history, History::default());
+    }
+}
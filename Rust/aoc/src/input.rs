@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use aoc_core::examples::{Example, ExampleError};
+use aoc_core::expected::{Answers, AnswersError};
+use aoc_core::Puzzle;
+use thiserror::Error;
+
+use crate::vault::{self, Key, VaultError};
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    Embedded,
+    Stdin,
+    File(PathBuf),
+    /// An example from the day's `examples/` directory, by name.
+    Example(String),
+}
+
+impl From<Option<PathBuf>> for InputSource {
+    fn from(path: Option<PathBuf>) -> Self {
+        match path {
+            None => Self::Embedded,
+            Some(path) if path == Path::new("-") => Self::Stdin,
+            Some(path) => Self::File(path),
+        }
+    }
+}
+
+impl InputSource {
+    pub fn path(&self, puzzle: &Puzzle) -> PathBuf {
+        match self {
+            InputSource::Embedded => Path::new(puzzle.dir).join(vault::ENCRYPTED_INPUT),
+            InputSource::Stdin => PathBuf::from("-"),
+            InputSource::File(path) => path.clone(),
+            InputSource::Example(name) => Path::new(puzzle.dir)
+                .join("examples")
+                .join(format!("{}.txt", name)),
+        }
+    }
+
+    /// Location of the `answers.toml` file that sits next to the input, if any.
+    pub fn answers_path(&self, puzzle: &Puzzle) -> Option<PathBuf> {
+        match self {
+            InputSource::Embedded => Some(Path::new(puzzle.dir).join("answers.toml")),
+            InputSource::Stdin => None,
+            InputSource::File(path) => Some(path.with_file_name("answers.toml")),
+            InputSource::Example(_) => None,
+        }
+    }
+
+    /// Known answers for the input: the `answers.toml` next to it, or the
+    /// examples manifest entry when running an example.
+    pub fn answers(&self, puzzle: &Puzzle) -> Result<Answers, InputError> {
+        match self {
+            InputSource::Example(name) => Ok(Example::load(Path::new(puzzle.dir), name)?.answers),
+            _ => match self.answers_path(puzzle) {
+                Some(path) => Ok(Answers::load(&path)?),
+                None => Ok(Answers::default()),
+            },
+        }
+    }
+
+    pub fn load(&self, puzzle: &Puzzle) -> Result<Cow<'static, str>, InputError> {
+        match self {
+            // Committed inputs are encrypted, but plaintext ones still work.
+            InputSource::Embedded if vault::is_encrypted(puzzle.input) => {
+                match Key::load(&vault::key_path()) {
+                    Ok(key) => Ok(Cow::Owned(key.decrypt(puzzle.input)?)),
+                    // Without the key, fall back to a decrypted copy of the input.
+                    Err(VaultError::Key { .. }) if plaintext_path(puzzle).is_file() => {
+                        InputSource::File(plaintext_path(puzzle)).load(puzzle)
+                    }
+                    Err(err) => Err(err.into()),
+                }
+            }
+            InputSource::Embedded => Ok(Cow::Borrowed(puzzle.input)),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| InputError::Stdin { source })?;
+                Ok(Cow::Owned(input))
+            }
+            InputSource::File(path) => {
+                std::fs::read_to_string(path)
+                    .map(Cow::Owned)
+                    .map_err(|source| InputError::File {
+                        path: path.clone(),
+                        source,
+                    })
+            }
+            InputSource::Example(name) => {
+                let example = Example::load(Path::new(puzzle.dir), name)?;
+                Ok(Cow::Owned(example.input))
+            }
+        }
+    }
+}
+
+fn plaintext_path(puzzle: &Puzzle) -> PathBuf {
+    Path::new(puzzle.dir).join(vault::PLAINTEXT_INPUT)
+}
+
+#[derive(Debug, Error)]
+pub enum InputError {
+    #[error("failed to read input from stdin: {source}")]
+    Stdin { source: io::Error },
+    #[error("failed to read input file {}: {source}", path.display())]
+    File { path: PathBuf, source: io::Error },
+    #[error("failed to read input directory {}: {source}", path.display())]
+    Directory { path: PathBuf, source: io::Error },
+    #[error(transparent)]
+    Example(#[from] ExampleError),
+    #[error(transparent)]
+    Answers(#[from] AnswersError),
+    #[error(transparent)]
+    Vault(#[from] VaultError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn input_source_from_path() {
+        assert_eq!(InputSource::from(None), InputSource::Embedded);
+        assert_eq!(
+            InputSource::from(Some(PathBuf::from("-"))),
+            InputSource::Stdin
+        );
+        assert_eq!(
+            InputSource::from(Some(PathBuf::from("input.txt"))),
+            InputSource::File(PathBuf::from("input.txt"))
+        );
+    }
+
+    #[test]
+    fn answers_path_next_to_input() {
+        let puzzle = crate::days::PUZZLES[0];
+        assert_eq!(
+            InputSource::Embedded.answers_path(&puzzle),
+            Some(Path::new(puzzle.dir).join("answers.toml"))
+        );
+        assert_eq!(InputSource::Stdin.answers_path(&puzzle), None);
+        assert_eq!(
+            InputSource::File(PathBuf::from("inputs/day-01.txt")).answers_path(&puzzle),
+            Some(PathBuf::from("inputs/answers.toml"))
+        );
+    }
+
+    #[test]
+    fn load_example() {
+        let puzzle = crate::days::PUZZLES[0];
+        let source = InputSource::Example(String::from("1"));
+        assert_eq!(
+            source.path(&puzzle),
+            Path::new(puzzle.dir).join("examples").join("1.txt")
+        );
+        assert!(source.load(&puzzle).unwrap().starts_with("1abc2"));
+        assert_eq!(
+            source.answers(&puzzle).unwrap().get(aoc_core::Part::One),
+            Some(&aoc_core::expected::Expected::Integer(142))
+        );
+    }
+
+    #[test]
+    fn load_unknown_example() {
+        let puzzle = crate::days::PUZZLES[0];
+        let source = InputSource::Example(String::from("99"));
+        let error = source.load(&puzzle).unwrap_err();
+        assert!(error.to_string().starts_with("failed to read "));
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let puzzle = crate::days::PUZZLES[0];
+        let source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        let error = source.load(&puzzle).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("failed to read input file does/not/exist.txt: "));
+    }
+}
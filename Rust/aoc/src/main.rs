@@ -0,0 +1,691 @@
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use aoc_core::expected::Answers;
+use aoc_core::{trace, Answer, Part, Puzzle};
+use batch::Outcome;
+use bench::Baseline;
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use client::{Client, DEFAULT_BASE_URL};
+use error::Error;
+use history::History;
+use input::{InputError, InputSource};
+use report::{Format, PartRecord, Record};
+use vault::Key;
+
+mod batch;
+mod bench;
+mod check;
+mod client;
+mod days;
+mod error;
+mod history;
+mod input;
+mod pool;
+mod report;
+mod tui;
+mod vault;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one or more puzzles and print their answers
+    Run(RunArgs),
+    /// Solve one or more puzzles and compare them against `answers.toml`
+    Check(SolveArgs),
+    /// Time parsing and solving a puzzle over repeated runs
+    Bench(BenchArgs),
+    /// Generate a random puzzle input for stress and scale testing
+    Gen(GenArgs),
+    /// Download a puzzle input from the Advent of Code website
+    Fetch(FetchArgs),
+    /// Submit an answer to the Advent of Code website
+    Submit(SubmitArgs),
+    /// Manage the encrypted puzzle inputs
+    #[command(subcommand)]
+    Input(InputCommand),
+    /// Browse the calendar and run days in an interactive dashboard
+    Tui,
+}
+
+#[derive(Subcommand, Debug)]
+enum InputCommand {
+    /// Generate the key used to encrypt and decrypt the inputs
+    Keygen,
+    /// Encrypt each day's input.txt into the committed input.txt.age
+    Encrypt(Selection),
+    /// Decrypt each day's input.txt.age into input.txt
+    Decrypt(Selection),
+}
+
+#[derive(Args, Debug)]
+#[group(required = true, multiple = false)]
+struct Selection {
+    #[arg(long, short = 'd', help = "Day of the calendar to run")]
+    day: Option<u8>,
+    #[arg(long, help = "Run every implemented day")]
+    all: bool,
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    #[command(flatten)]
+    solve: SolveArgs,
+    #[arg(
+        long,
+        help = "Compare the answers against the answers.toml next to the input"
+    )]
+    check: bool,
+}
+
+#[derive(Args, Debug)]
+struct SolveArgs {
+    #[command(flatten)]
+    selection: Selection,
+    #[arg(
+        long,
+        short = 'p',
+        help = "Problem part number (1 or 2), both parts if omitted"
+    )]
+    part: Option<Part>,
+    #[arg(
+        long,
+        short = 'i',
+        value_name = "PATH",
+        conflicts_with = "all",
+        help = "Read the puzzle input from a file, or from stdin with '-'"
+    )]
+    input: Option<PathBuf>,
+    #[arg(
+        long,
+        short = 'e',
+        value_name = "N",
+        conflicts_with_all = ["all", "input"],
+        help = "Run against examples/N.txt instead of the puzzle input"
+    )]
+    example: Option<String>,
+    #[arg(
+        long,
+        short = 'f',
+        value_enum,
+        default_value_t = Format::Table,
+        help = "Output format"
+    )]
+    format: Format,
+    #[arg(
+        long,
+        value_name = "DIR",
+        conflicts_with_all = ["all", "input", "example", "explain"],
+        help = "Solve every input in a directory, checking each one against its <name>.answers"
+    )]
+    inputs: Option<PathBuf>,
+    #[arg(long, help = "Show how each part reached its answer")]
+    explain: bool,
+    #[arg(
+        long,
+        short = 'j',
+        value_name = "N",
+        default_value_t = 1,
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Number of parts to solve at the same time"
+    )]
+    jobs: u32,
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    #[arg(long, short = 'd', help = "Day of the calendar to benchmark")]
+    day: u8,
+    #[arg(
+        long,
+        short = 'p',
+        help = "Problem part number (1 or 2), both parts if omitted"
+    )]
+    part: Option<Part>,
+    #[arg(
+        long,
+        short = 'n',
+        default_value_t = 10,
+        value_parser = clap::value_parser!(u32).range(1..),
+        help = "Number of measured iterations"
+    )]
+    iterations: u32,
+    #[arg(
+        long,
+        short = 'i',
+        value_name = "PATH",
+        help = "Read the puzzle input from a file, or from stdin with '-'"
+    )]
+    input: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Add the measurements to a baseline JSON file"
+    )]
+    save_baseline: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Compare the measurements against a baseline JSON file"
+    )]
+    baseline: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        default_value_t = 10.0,
+        help = "Median slowdown allowed before flagging a regression"
+    )]
+    threshold: f64,
+}
+
+#[derive(Args, Debug)]
+struct GenArgs {
+    #[arg(
+        long,
+        short = 'd',
+        help = "Day of the calendar to generate an input for"
+    )]
+    day: u8,
+    #[arg(
+        long,
+        short = 's',
+        default_value_t = 100,
+        help = "Size of the input, its meaning depends on the day"
+    )]
+    size: usize,
+    #[arg(long, help = "Seed for the random generator, a random one if omitted")]
+    seed: Option<u64>,
+    #[arg(
+        long,
+        short = 'o',
+        value_name = "PATH",
+        help = "Write the input to a file instead of stdout"
+    )]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+struct ServerArgs {
+    #[arg(
+        long,
+        env = "AOC_BASE_URL",
+        default_value = DEFAULT_BASE_URL,
+        help = "Website to talk to, e.g. a local aoc-mock server"
+    )]
+    base_url: String,
+    #[arg(
+        long,
+        env = "AOC_SESSION",
+        hide_env_values = true,
+        help = "Value of the website's session cookie"
+    )]
+    session: String,
+}
+
+#[derive(Args, Debug)]
+struct FetchArgs {
+    #[arg(long, short = 'd', help = "Day of the calendar to fetch")]
+    day: u8,
+    #[arg(
+        long,
+        short = 'o',
+        value_name = "PATH",
+        help = "Where to write the plaintext input, '-' for stdout [default: the day's encrypted input.txt.age]"
+    )]
+    output: Option<PathBuf>,
+    #[arg(long, help = "Overwrite an existing input file")]
+    force: bool,
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+#[derive(Args, Debug)]
+struct SubmitArgs {
+    #[arg(long, short = 'd', help = "Day of the calendar to submit")]
+    day: u8,
+    #[arg(long, short = 'p', help = "Problem part number (1 or 2)")]
+    part: Part,
+    #[arg(
+        long,
+        short = 'a',
+        value_parser = Answer::from_str,
+        help = "Answer to submit, solved from the day's input if omitted"
+    )]
+    answer: Option<Answer>,
+    #[arg(
+        long,
+        short = 'i',
+        value_name = "PATH",
+        conflicts_with = "answer",
+        help = "Solve the puzzle input from a file, or from stdin with '-'"
+    )]
+    input: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Log of earlier submissions [default: the day's submissions.toml]"
+    )]
+    history: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "Submit even if earlier submissions show the answer is wrong"
+    )]
+    force: bool,
+    #[command(flatten)]
+    server: ServerArgs,
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Run(args) => run(args.solve, args.check),
+        Command::Check(args) => run(args, true),
+        Command::Bench(args) => bench(args),
+        Command::Gen(args) => generate(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::Input(InputCommand::Keygen) => keygen(),
+        Command::Input(InputCommand::Encrypt(selection)) => encrypt(&selection),
+        Command::Input(InputCommand::Decrypt(selection)) => decrypt(&selection),
+        Command::Tui => tui::run().map_err(Error::Terminal),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// A day to solve, with its input loaded.
+struct Day {
+    puzzle: &'static Puzzle,
+    input: Cow<'static, str>,
+    answers: Answers,
+    parts: Vec<Part>,
+}
+
+fn run(args: SolveArgs, check: bool) -> Result<(), Error> {
+    if let Some(dir) = &args.inputs {
+        return run_batch(&args, dir);
+    }
+
+    let puzzles = select(&args.selection);
+    let source = match args.example {
+        Some(name) => InputSource::Example(name),
+        None => InputSource::from(args.input),
+    };
+
+    let mut days = Vec::new();
+    for puzzle in puzzles {
+        let input = source.load(puzzle)?;
+        let answers = match (&source, check) {
+            (InputSource::Example(_), _) | (_, true) => source.answers(puzzle)?,
+            _ => Answers::default(),
+        };
+        // Examples often only apply to one of the parts, so only solve the
+        // parts they have answers for.
+        let parts = match (args.part, &source) {
+            (Some(part), _) => vec![part],
+            (None, InputSource::Example(_)) => answers.parts(),
+            (None, _) => vec![Part::One, Part::Two],
+        };
+        days.push(Day {
+            puzzle,
+            input,
+            answers,
+            parts,
+        });
+    }
+
+    // Days are the unit of work, so both parts of a day still share a single
+    // parse when they run on the pool.
+    let start = Instant::now();
+    let runs = pool::map(&days, args.jobs as usize, |day| {
+        let solve = || day.puzzle.solve(&day.input, &day.parts);
+        match args.explain {
+            true => trace::capture(solve).0,
+            false => solve(),
+        }
+    });
+    let wall_time = start.elapsed();
+
+    let mut records = Vec::new();
+    let mut failed = 0;
+    let mut errors = 0;
+
+    for (day, run) in days.iter().zip(runs) {
+        let input_path = source.path(day.puzzle).display().to_string();
+        // A day that fails to solve is reported in its row, without holding
+        // back the results of the other days.
+        let record = match run {
+            Ok(run) => {
+                let parts: Vec<PartRecord> = run
+                    .parts
+                    .into_iter()
+                    .map(|solved| PartRecord::new(solved, &day.answers, check))
+                    .collect();
+                failed += parts.iter().filter(|part| part.expected.is_some()).count();
+
+                Record {
+                    day: day.puzzle.day,
+                    input_path,
+                    parse_time: run.parse_time,
+                    parts,
+                    error: None,
+                }
+            }
+            Err(err) => {
+                errors += 1;
+                if args.format == Format::Json {
+                    eprintln!("error: day {}: {}", day.puzzle.day, err);
+                }
+
+                Record {
+                    day: day.puzzle.day,
+                    input_path,
+                    parse_time: Duration::ZERO,
+                    parts: Vec::new(),
+                    error: Some(err.to_string()),
+                }
+            }
+        };
+        records.push(record);
+    }
+
+    print!("{}", report::render(&records, args.format, check));
+    if args.format == Format::Table && records.len() > 1 {
+        print!("{}", report::summary(&records, wall_time, args.jobs));
+    }
+
+    match (errors, failed) {
+        (0, 0) => Ok(()),
+        (0, failed) => Err(Error::CheckFailed { failed }),
+        (errors, _) => Err(Error::SolveFailed { errors }),
+    }
+}
+
+fn run_batch(args: &SolveArgs, dir: &Path) -> Result<(), Error> {
+    let puzzle = select(&args.selection)[0];
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut inputs = Vec::new();
+    for entry in batch::discover(dir)? {
+        let input = InputSource::File(entry.path.clone()).load(puzzle)?;
+        let answers = Answers::load(&entry.answers_path()).map_err(InputError::from)?;
+        inputs.push((entry, input, answers));
+    }
+
+    let outcomes: Vec<Outcome> =
+        pool::map(&inputs, args.jobs as usize, |(entry, input, answers)| {
+            let result = puzzle.solve(input, &parts).map(|run| Record {
+                day: puzzle.day,
+                input_path: entry.path.display().to_string(),
+                parse_time: run.parse_time,
+                parts: run
+                    .parts
+                    .into_iter()
+                    .map(|solved| PartRecord::new(solved, answers, true))
+                    .collect(),
+                error: None,
+            });
+            Outcome {
+                entry: entry.clone(),
+                result,
+            }
+        });
+    let mismatched = outcomes
+        .iter()
+        .filter(|outcome| outcome.mismatched())
+        .count();
+
+    match args.format {
+        Format::Table => print!("{}", batch::render(&outcomes, &parts)),
+        Format::Json => {
+            // Inputs that failed to solve have no record, so they are only
+            // reported on stderr.
+            let mut records = Vec::new();
+            for outcome in outcomes {
+                match outcome.result {
+                    Ok(record) => records.push(record),
+                    Err(err) => eprintln!("error: {}: {}", outcome.entry.name, err),
+                }
+            }
+            print!("{}", report::render(&records, Format::Json, true));
+        }
+    }
+
+    match mismatched {
+        0 => Ok(()),
+        inputs => Err(Error::Mismatched { inputs }),
+    }
+}
+
+fn bench(args: BenchArgs) -> Result<(), Error> {
+    let puzzle = select(&Selection {
+        day: Some(args.day),
+        all: false,
+    })[0];
+    let input = InputSource::from(args.input).load(puzzle)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+    let baseline = args.baseline.as_deref().map(Baseline::load).transpose()?;
+
+    let measurements = vec![
+        bench::measure(puzzle, &input, &parts, args.iterations as usize).map_err(|source| {
+            Error::Solve {
+                day: puzzle.day,
+                source,
+            }
+        })?,
+    ];
+
+    let (table, regressions) = bench::render(&measurements, baseline.as_ref(), args.threshold);
+    print!("{}", table);
+
+    if let Some(path) = &args.save_baseline {
+        let mut saved = Baseline::load_or_default(path)?;
+        for measurement in measurements {
+            saved.merge(measurement);
+        }
+        saved.save(path)?;
+    }
+
+    match regressions {
+        0 => Ok(()),
+        regressions => Err(Error::Regressed { regressions }),
+    }
+}
+
+fn generate(args: GenArgs) -> Result<(), Error> {
+    let puzzle = select(&Selection {
+        day: Some(args.day),
+        all: false,
+    })[0];
+    let seed = args.seed.unwrap_or_else(rand::random);
+    let input = puzzle
+        .generate(seed, args.size)
+        .ok_or(Error::NoGenerator { day: puzzle.day })?;
+
+    // The seed is needed to generate the same input again, e.g. after a
+    // failure, so it's reported without mixing it into the input.
+    eprintln!(
+        "day {} input of size {} with seed {}",
+        puzzle.day, args.size, seed
+    );
+    match &args.output {
+        Some(path) => write(path, &input),
+        None => {
+            println!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn fetch(args: FetchArgs) -> Result<(), Error> {
+    // Inputs in the days' directories are committed, so they are encrypted.
+    let (output, encrypt) = match args.output {
+        Some(path) => (path, false),
+        None => match days::find(args.day) {
+            Some(puzzle) => (Path::new(puzzle.dir).join(vault::ENCRYPTED_INPUT), true),
+            None => Cli::command()
+                .error(
+                    ErrorKind::MissingRequiredArgument,
+                    format!("day {} is not implemented, pass --output", args.day),
+                )
+                .exit(),
+        },
+    };
+    let key = match encrypt {
+        true => Some(Key::load(&vault::key_path())?),
+        false => None,
+    };
+
+    // Days are committed with an empty input.txt.age, so only a non-empty
+    // file counts as already fetched.
+    let exists = std::fs::metadata(&output).is_ok_and(|metadata| metadata.len() > 0);
+    if output != Path::new("-") && exists && !args.force {
+        return Err(Error::InputExists { path: output });
+    }
+
+    let client = Client::new(&args.server.base_url, &args.server.session);
+    let mut input = client.fetch_input(args.day)?;
+    if let Some(key) = &key {
+        input = key.encrypt(&input);
+    }
+
+    if output == Path::new("-") {
+        print!("{}", input);
+    } else {
+        write(&output, &input)?;
+        println!("wrote day {} input to {}", args.day, output.display());
+    }
+    Ok(())
+}
+
+fn submit(args: SubmitArgs) -> Result<(), Error> {
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let puzzle = select(&Selection {
+                day: Some(args.day),
+                all: false,
+            })[0];
+            let input = InputSource::from(args.input).load(puzzle)?;
+            let mut run = puzzle
+                .solve(&input, &[args.part])
+                .map_err(|source| Error::Solve {
+                    day: puzzle.day,
+                    source,
+                })?;
+            run.parts.remove(0).answer
+        }
+    };
+
+    let history_path = args.history.or_else(|| {
+        days::find(args.day).map(|puzzle| Path::new(puzzle.dir).join(history::FILE_NAME))
+    });
+    let mut history = match &history_path {
+        Some(path) => History::load(path)?,
+        None => History::default(),
+    };
+
+    if let Some(conflict) = history.check(args.part, &answer) {
+        if !args.force {
+            return Err(Error::Guarded { answer, conflict });
+        }
+        eprintln!("warning: {} is {}, submitting anyway", answer, conflict);
+    }
+
+    let client = Client::new(&args.server.base_url, &args.server.session);
+    let verdict = client.submit(args.day, args.part, &answer)?;
+    println!(
+        "day {} part {}: {} is {}",
+        args.day, args.part, answer, verdict
+    );
+
+    if let Some(path) = &history_path {
+        if history.record(args.part, &answer, &verdict) {
+            history.save(path)?;
+        }
+    }
+
+    match verdict.is_correct() {
+        true => Ok(()),
+        false => Err(Error::Rejected { verdict }),
+    }
+}
+
+fn keygen() -> Result<(), Error> {
+    let path = vault::key_path();
+    let key = Key::generate();
+    key.save(&path)?;
+    println!("wrote key for {} to {}", key.public(), path.display());
+    Ok(())
+}
+
+fn encrypt(selection: &Selection) -> Result<(), Error> {
+    let key = Key::load(&vault::key_path())?;
+    for puzzle in select(selection) {
+        let dir = Path::new(puzzle.dir);
+        let input = InputSource::File(dir.join(vault::PLAINTEXT_INPUT)).load(puzzle)?;
+        let output = dir.join(vault::ENCRYPTED_INPUT);
+        write(&output, &key.encrypt(&input))?;
+        println!("encrypted day {} input to {}", puzzle.day, output.display());
+    }
+    Ok(())
+}
+
+fn decrypt(selection: &Selection) -> Result<(), Error> {
+    let key = Key::load(&vault::key_path())?;
+    for puzzle in select(selection) {
+        let dir = Path::new(puzzle.dir);
+        let input = InputSource::File(dir.join(vault::ENCRYPTED_INPUT)).load(puzzle)?;
+        let input = match vault::is_encrypted(&input) {
+            true => key.decrypt(&input)?,
+            false => input.into_owned(),
+        };
+        let output = dir.join(vault::PLAINTEXT_INPUT);
+        write(&output, &input)?;
+        println!("decrypted day {} input to {}", puzzle.day, output.display());
+    }
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    std::fs::write(path, contents).map_err(|source| Error::Write {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn select(selection: &Selection) -> Vec<&'static Puzzle> {
+    match selection.day {
+        Some(day) => match days::find(day) {
+            Some(puzzle) => vec![puzzle],
+            None => Cli::command()
+                .error(
+                    ErrorKind::InvalidValue,
+                    format!("day {} is not implemented", day),
+                )
+                .exit(),
+        },
+        None => days::PUZZLES.iter().collect(),
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Applies `f` to every item on up to `jobs` scoped threads, each taking the
+/// next unclaimed item when it's done, and returns the results in the order
+/// of `items`. With a single job everything runs on the calling thread.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    if jobs <= 1 || items.len() <= 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(items.iter().map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+                let result = f(item);
+                results.lock().expect("no worker panicked")[index] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("no worker panicked")
+        .into_iter()
+        .map(|result| result.expect("every item was mapped"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(1)]
+    #[case(3)]
+    #[case(20)]
+    fn map_keeps_order(#[case] jobs: usize) {
+        let items: Vec<u64> = (0..10).collect();
+        let result = map(&items, jobs, |item| {
+            // Later items finish first.
+            thread::sleep(Duration::from_millis(10 - item));
+            item * 2
+        });
+        assert_eq!(result, (0..20).step_by(2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn map_without_items() {
+        assert_eq!(map(&[] as &[u8], 4, |item| *item), Vec::<u8>::new());
+    }
+}
@@ -0,0 +1,420 @@
+use std::cmp::Reverse;
+use std::fmt;
+use std::time::Duration;
+
+use aoc_core::{Answer, Part, Solved};
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+
+use aoc_core::expected::{Answers, Expected};
+
+use crate::check::Status;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable table with parse and solve times
+    Table,
+    /// JSON array with one object per solved part
+    Json,
+}
+
+/// The outcome of solving a day, as reported by the CLI. The input is parsed
+/// once and shared by every solved part.
+#[derive(Debug)]
+pub struct Record {
+    pub day: u8,
+    pub input_path: String,
+    pub parse_time: Duration,
+    pub parts: Vec<PartRecord>,
+    /// Why the day failed to parse or solve, in which case it has no parts.
+    pub error: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct PartRecord {
+    pub part: Part,
+    pub answer: Answer,
+    pub solve_time: Duration,
+    pub check: Option<Status>,
+    pub expected: Option<Expected>,
+    pub trace: Vec<String>,
+}
+
+/// A solved part as written in JSON, repeating the parse time and input
+/// path of its day so every object stands on its own.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    day: u8,
+    #[serde(serialize_with = "part_number")]
+    part: Part,
+    #[serde(serialize_with = "answer_value")]
+    answer: Answer,
+    #[serde(rename = "parse_time_ns", serialize_with = "nanoseconds")]
+    parse_time: Duration,
+    #[serde(rename = "solve_time_ns", serialize_with = "nanoseconds")]
+    solve_time: Duration,
+    input_path: &'a str,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "status_label"
+    )]
+    check: Option<Status>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<Expected>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    trace: Vec<String>,
+}
+
+impl PartRecord {
+    /// Records a solved part, checked against `answers` if `check` is set.
+    pub fn new(solved: Solved, answers: &Answers, check: bool) -> Self {
+        let status = check.then(|| Status::new(answers, solved.part, &solved.answer));
+        let expected = match &status {
+            Some(Status::Fail(expected)) => Some(expected.clone()),
+            _ => None,
+        };
+
+        PartRecord {
+            part: solved.part,
+            answer: solved.answer,
+            solve_time: solved.solve_time,
+            check: status,
+            expected,
+            trace: solved.trace,
+        }
+    }
+}
+
+pub fn render(records: &[Record], format: Format, check: bool) -> String {
+    match format {
+        Format::Table => {
+            let mut header = vec!["Day", "Part", "Answer", "Parse", "Solve"];
+            if check {
+                header.push("Check");
+            }
+
+            let mut table = Table::new(header);
+            for record in records {
+                if let Some(error) = &record.error {
+                    let mut row = vec![
+                        record.day.to_string(),
+                        String::new(),
+                        format!("error: {}", error),
+                        String::new(),
+                        String::new(),
+                    ];
+                    if check {
+                        row.push(String::from("ERROR"));
+                    }
+                    table.push(row);
+                }
+                for (index, part) in record.parts.iter().enumerate() {
+                    // The parse time is shown once, on the first part of the day.
+                    let parse_time = match index {
+                        0 => format!("{:.2?}", record.parse_time),
+                        _ => String::new(),
+                    };
+                    let mut row = vec![
+                        record.day.to_string(),
+                        part.part.to_string(),
+                        part.answer.to_string(),
+                        parse_time,
+                        format!("{:.2?}", part.solve_time),
+                    ];
+                    if let Some(status) = &part.check {
+                        row.push(status.to_string());
+                    }
+                    table.push(row);
+                }
+            }
+
+            let mut output = table.to_string();
+            for record in records {
+                for part in record.parts.iter().filter(|part| !part.trace.is_empty()) {
+                    output.push_str(&format!("\nDay {} part {}:\n", record.day, part.part));
+                    for line in part.trace.iter() {
+                        output.push_str(&format!("  {}\n", line));
+                    }
+                }
+            }
+            output
+        }
+        Format::Json => {
+            let records: Vec<JsonRecord> = records
+                .iter()
+                .flat_map(|record| {
+                    record.parts.iter().map(|part| JsonRecord {
+                        day: record.day,
+                        part: part.part,
+                        answer: part.answer.clone(),
+                        parse_time: record.parse_time,
+                        solve_time: part.solve_time,
+                        input_path: &record.input_path,
+                        check: part.check.clone(),
+                        expected: part.expected.clone(),
+                        trace: part.trace.clone(),
+                    })
+                })
+                .collect();
+            let mut json = serde_json::to_string_pretty(&records).expect("records serialize");
+            json.push('\n');
+            json
+        }
+    }
+}
+
+/// Parts slower than most others, flagged by [`summary`].
+const SLOWEST: usize = 3;
+
+/// Totals of a run over several days, naming its slowest parts.
+pub fn summary(records: &[Record], wall_time: Duration, jobs: u32) -> String {
+    let mut parts: Vec<(u8, &PartRecord)> = records
+        .iter()
+        .flat_map(|record| record.parts.iter().map(move |part| (record.day, part)))
+        .collect();
+    let busy_time = records
+        .iter()
+        .map(|record| record.parse_time)
+        .sum::<Duration>()
+        + parts
+            .iter()
+            .map(|(_, part)| part.solve_time)
+            .sum::<Duration>();
+
+    parts.sort_by_key(|(_, part)| Reverse(part.solve_time));
+    let slowest: Vec<String> = parts
+        .iter()
+        .take(SLOWEST)
+        .map(|(day, part)| format!("day {} part {} ({:.2?})", day, part.part, part.solve_time))
+        .collect();
+
+    let mut output = format!(
+        "\n{} parts in {:.2?} on {} {}, {:.2?} spent parsing and solving\nSlowest: {}\n",
+        parts.len(),
+        wall_time,
+        jobs,
+        if jobs == 1 { "thread" } else { "threads" },
+        busy_time,
+        slowest.join(", ")
+    );
+
+    let failed: Vec<String> = records
+        .iter()
+        .filter(|record| record.error.is_some())
+        .map(|record| format!("day {}", record.day))
+        .collect();
+    if !failed.is_empty() {
+        output.push_str(&format!("Failed: {}\n", failed.join(", ")));
+    }
+    output
+}
+
+fn part_number<S: Serializer>(part: &Part, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8(u8::from(*part))
+}
+
+fn answer_value<S: Serializer>(answer: &Answer, serializer: S) -> Result<S::Ok, S::Error> {
+    match answer {
+        Answer::Unsigned(value) => serializer.serialize_u64(*value),
+        Answer::Signed(value) => serializer.serialize_i64(*value),
+        Answer::Big(value) => serializer.serialize_u128(*value),
+        Answer::Text(value) => serializer.serialize_str(value),
+    }
+}
+
+fn nanoseconds<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+fn status_label<S: Serializer>(status: &Option<Status>, serializer: S) -> Result<S::Ok, S::Error> {
+    match status {
+        Some(status) => serializer.serialize_str(status.label()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Plain text table with left-aligned, space-padded columns.
+pub struct Table {
+    header: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: Vec<&'static str>) -> Self {
+        Table {
+            header,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut widths: Vec<usize> = self.header.iter().map(|cell| cell.len()).collect();
+        for row in self.rows.iter() {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let header = self.header.iter().map(|cell| cell.to_string()).collect();
+        for row in std::iter::once(&header).chain(self.rows.iter()) {
+            let line = row
+                .iter()
+                .zip(widths.iter())
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join("  ");
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(check: Option<Status>) -> Record {
+        Record {
+            day: 1,
+            input_path: String::from("day-01/input.txt"),
+            parse_time: Duration::from_nanos(1500),
+            parts: vec![
+                PartRecord {
+                    part: Part::One,
+                    answer: Answer::from(142u32),
+                    solve_time: Duration::from_micros(20),
+                    expected: None,
+                    check: check.clone(),
+                    trace: Vec::new(),
+                },
+                PartRecord {
+                    part: Part::Two,
+                    answer: Answer::from(281u32),
+                    solve_time: Duration::from_micros(35),
+                    expected: None,
+                    check,
+                    trace: Vec::new(),
+                },
+            ],
+            error: None,
+        }
+    }
+
+    #[test]
+    fn table_pads_columns() {
+        let mut table = Table::new(vec!["Day", "Answer", "Check"]);
+        table.push(vec!["1".into(), "142".into(), "PASS".into()]);
+        table.push(vec!["12".into(), "7".into(), "".into()]);
+        assert_eq!(
+            table.to_string(),
+            "Day  Answer  Check\n1    142     PASS\n12   7\n"
+        );
+    }
+
+    #[test]
+    fn render_table_with_times() {
+        let result = render(&[record(Some(Status::Pass))], Format::Table, true);
+        assert_eq!(
+            result,
+            "Day  Part  Answer  Parse   Solve    Check\n\
+             1    1     142     1.50µs  20.00µs  PASS\n\
+             1    2     281             35.00µs  PASS\n"
+        );
+    }
+
+    #[test]
+    fn render_table_with_trace() {
+        let mut record = record(None);
+        record.parts[1].trace = vec![String::from("line 1: 2 and 9 make 29")];
+        let result = render(&[record], Format::Table, false);
+        assert_eq!(
+            result,
+            "Day  Part  Answer  Parse   Solve\n\
+             1    1     142     1.50µs  20.00µs\n\
+             1    2     281             35.00µs\n\
+             \n\
+             Day 1 part 2:\n  \
+             line 1: 2 and 9 make 29\n"
+        );
+    }
+
+    #[test]
+    fn render_json_with_trace() {
+        let mut record = record(None);
+        record.parts[0].trace = vec![String::from("line 1: 1 and 2 make 12")];
+        let result = render(&[record], Format::Json, false);
+        let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(
+            value[0]["trace"],
+            serde_json::json!(["line 1: 1 and 2 make 12"])
+        );
+        assert_eq!(value[1].get("trace"), None);
+    }
+
+    #[test]
+    fn summary_flags_slowest_parts() {
+        let mut second = record(None);
+        second.day = 2;
+        second.parts[0].solve_time = Duration::from_millis(3);
+        let result = summary(&[record(None), second], Duration::from_millis(2), 4);
+        assert_eq!(
+            result,
+            "\n4 parts in 2.00ms on 4 threads, 3.09ms spent parsing and solving\n\
+             Slowest: day 2 part 1 (3.00ms), day 1 part 2 (35.00µs), day 2 part 2 (35.00µs)\n"
+        );
+    }
+
+    #[test]
+    fn render_table_with_failed_day() {
+        let failed = Record {
+            day: 2,
+            input_path: String::from("day-02/input.txt"),
+            parse_time: Duration::ZERO,
+            parts: Vec::new(),
+            error: Some(String::from("line 1, column 1: expected Game")),
+        };
+        let records = [record(Some(Status::Pass)), failed];
+        assert_eq!(
+            render(&records, Format::Table, true),
+            "Day  Part  Answer                                  Parse   Solve    Check\n\
+             1    1     142                                     1.50µs  20.00µs  PASS\n\
+             1    2     281                                             35.00µs  PASS\n\
+             2          error: line 1, column 1: expected Game                   ERROR\n"
+        );
+        assert!(summary(&records, Duration::from_millis(1), 1).ends_with("Failed: day 2\n"));
+    }
+
+    #[test]
+    fn render_json() {
+        let result = render(&[record(None)], Format::Json, false);
+        let value: serde_json::Value = serde_json::from_str(&result).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([
+                {
+                    "day": 1,
+                    "part": 1,
+                    "answer": 142,
+                    "parse_time_ns": 1500,
+                    "solve_time_ns": 20000,
+                    "input_path": "day-01/input.txt",
+                },
+                {
+                    "day": 1,
+                    "part": 2,
+                    "answer": 281,
+                    "parse_time_ns": 1500,
+                    "solve_time_ns": 35000,
+                    "input_path": "day-01/input.txt",
+                },
+            ])
+        );
+    }
+}
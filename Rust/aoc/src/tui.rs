@@ -0,0 +1,753 @@
+use std::any::Any;
+use std::io;
+use std::panic::{self, AssertUnwindSafe, PanicHookInfo};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::expected::Answers;
+use aoc_core::{examples, trace, Part, Puzzle};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::ExecutableCommand;
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph};
+use ratatui::{Frame, Terminal};
+
+use crate::check::Status;
+use crate::days;
+use crate::input::InputSource;
+use crate::report::{self, Format, PartRecord, Record};
+
+const CALENDAR: u8 = 25;
+
+/// How often the screen is redrawn while waiting for a key or a run.
+const TICK: Duration = Duration::from_millis(100);
+
+/// Name of the threads days are solved on.
+const SOLVER_THREAD: &str = "solver";
+
+/// Shows the dashboard until the user quits, restoring the terminal even if
+/// drawing fails or something panics.
+pub fn run() -> io::Result<()> {
+    struct Screen;
+
+    impl Drop for Screen {
+        fn drop(&mut self) {
+            restore();
+        }
+    }
+
+    terminal::enable_raw_mode()?;
+    let _screen = Screen;
+    io::stdout().execute(EnterAlternateScreen)?;
+    let _hook = PanicHook::install(restore);
+
+    let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let mut app = App::new();
+
+    loop {
+        app.poll();
+        terminal.draw(|frame| app.draw(frame))?;
+
+        if event::poll(TICK)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !app.handle(key) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+type Hook = dyn Fn(&PanicHookInfo<'_>) + Send + Sync + 'static;
+
+/// The panic hook while the dashboard is up. Dropping it puts back the one
+/// it replaced.
+struct PanicHook {
+    previous: Arc<Hook>,
+}
+
+impl PanicHook {
+    /// A panicking solver is caught and shown as a failed run, so its message
+    /// is kept off the dashboard. Any other panic ends the dashboard, so
+    /// `restore` puts the terminal back before the message is printed.
+    fn install(restore: fn()) -> Self {
+        let previous: Arc<Hook> = Arc::from(panic::take_hook());
+        let hook = Arc::clone(&previous);
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(SOLVER_THREAD) {
+                restore();
+                hook(info);
+            }
+        }));
+        PanicHook { previous }
+    }
+}
+
+impl Drop for PanicHook {
+    fn drop(&mut self) {
+        // Changing hooks panics while unwinding, and the panic has already
+        // been passed on to the previous hook anyway.
+        if thread::panicking() {
+            return;
+        }
+        drop(panic::take_hook());
+        let previous = Arc::clone(&self.previous);
+        panic::set_hook(Box::new(move |info| previous(info)));
+    }
+}
+
+/// Leaves raw mode and the alternate screen.
+fn restore() {
+    let _ = terminal::disable_raw_mode();
+    let _ = io::stdout().execute(LeaveAlternateScreen);
+}
+
+/// A day of the calendar, whether implemented or not.
+struct Day {
+    number: u8,
+    puzzle: Option<&'static Puzzle>,
+    /// Number of parts with an answer in the day's `answers.toml`.
+    known: usize,
+    last: Option<Outcome>,
+}
+
+/// The latest run of a day from the dashboard.
+enum Outcome {
+    Solved {
+        source: InputSource,
+        record: Record,
+        trace: Vec<String>,
+    },
+    Failed {
+        source: InputSource,
+        message: String,
+    },
+}
+
+impl Outcome {
+    fn source(&self) -> &InputSource {
+        match self {
+            Outcome::Solved { source, .. } | Outcome::Failed { source, .. } => source,
+        }
+    }
+
+    /// Outcome of checking the answers to the real puzzle input, which is
+    /// what the calendar shows next to the day.
+    fn check_label(&self) -> &'static str {
+        match self {
+            _ if *self.source() != InputSource::Embedded => "",
+            Outcome::Failed { .. } => "ERROR",
+            Outcome::Solved { record, .. } => {
+                let statuses = record.parts.iter().map(|part| &part.check);
+                if statuses
+                    .clone()
+                    .any(|check| matches!(check, Some(Status::Fail(_))))
+                {
+                    "FAIL"
+                } else if statuses.clone().all(|check| check == &Some(Status::Pass)) {
+                    "PASS"
+                } else {
+                    ""
+                }
+            }
+        }
+    }
+}
+
+struct Running {
+    day: u8,
+    started: Instant,
+    receiver: Receiver<Outcome>,
+}
+
+pub struct App {
+    days: Vec<Day>,
+    list: ListState,
+    /// Part to solve, both if `None`.
+    part: Option<Part>,
+    source: InputSource,
+    /// Last input path typed in, offered again when cycling through inputs.
+    custom: Option<PathBuf>,
+    /// Input path being typed in.
+    editing: Option<String>,
+    explain: bool,
+    scroll: u16,
+    running: Option<Running>,
+}
+
+impl App {
+    pub fn new() -> Self {
+        let days = (1..=CALENDAR)
+            .map(|number| {
+                let puzzle = days::find(number);
+                let known = puzzle
+                    .and_then(|puzzle| InputSource::Embedded.answers(puzzle).ok())
+                    .map_or(0, |answers| answers.parts().len());
+                Day {
+                    number,
+                    puzzle,
+                    known,
+                    last: None,
+                }
+            })
+            .collect();
+
+        App {
+            days,
+            list: ListState::default().with_selected(Some(0)),
+            part: None,
+            source: InputSource::Embedded,
+            custom: None,
+            editing: None,
+            explain: false,
+            scroll: 0,
+            running: None,
+        }
+    }
+
+    fn selected(&self) -> &Day {
+        &self.days[self.list.selected().unwrap_or(0)]
+    }
+
+    /// Applies a key press, returning `false` once the user quits.
+    pub fn handle(&mut self, key: KeyEvent) -> bool {
+        if let Some(path) = &mut self.editing {
+            match key.code {
+                KeyCode::Char(c) => path.push(c),
+                KeyCode::Backspace => {
+                    path.pop();
+                }
+                KeyCode::Enter if !path.is_empty() => {
+                    let path = PathBuf::from(path.as_str());
+                    self.source = InputSource::File(path.clone());
+                    self.custom = Some(path);
+                    self.editing = None;
+                }
+                KeyCode::Esc => self.editing = None,
+                _ => {}
+            }
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up | KeyCode::Char('k') => self.select(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.select(1),
+            KeyCode::Char('p') => {
+                self.part = match self.part {
+                    None => Some(Part::One),
+                    Some(Part::One) => Some(Part::Two),
+                    Some(Part::Two) => None,
+                }
+            }
+            KeyCode::Char('i') => self.next_source(),
+            KeyCode::Char('c') => {
+                let path = self.custom.as_ref().map(|path| path.display().to_string());
+                self.editing = Some(path.unwrap_or_default());
+            }
+            KeyCode::Char('e') => self.explain = !self.explain,
+            KeyCode::Enter | KeyCode::Char('r') => self.start(),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            _ => {}
+        }
+        true
+    }
+
+    fn select(&mut self, offset: isize) {
+        let index = self.list.selected().unwrap_or(0);
+        let index = index.saturating_add_signed(offset).min(self.days.len() - 1);
+        self.list.select(Some(index));
+        self.scroll = 0;
+
+        // Examples belong to the day they were picked for.
+        if let InputSource::Example(_) = self.source {
+            self.source = InputSource::Embedded;
+        }
+    }
+
+    /// Moves on to the next input: the puzzle input, each of the day's
+    /// examples, then the last custom path.
+    fn next_source(&mut self) {
+        let mut sources = vec![InputSource::Embedded];
+        if let Some(puzzle) = self.selected().puzzle {
+            let names = examples::names(std::path::Path::new(puzzle.dir)).unwrap_or_default();
+            sources.extend(names.into_iter().map(InputSource::Example));
+        }
+        sources.extend(self.custom.clone().map(InputSource::File));
+
+        let index = sources.iter().position(|source| *source == self.source);
+        let next = index.map_or(0, |index| (index + 1) % sources.len());
+        self.source = sources.swap_remove(next);
+    }
+
+    /// Solves the selected day on a background thread, so that slow days
+    /// don't freeze the dashboard. Only one day runs at a time.
+    fn start(&mut self) {
+        let day = self.selected();
+        let Some(puzzle) = day.puzzle else {
+            return;
+        };
+        if self.running.is_some() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let (source, part, explain) = (self.source.clone(), self.part, self.explain);
+        thread::Builder::new()
+            .name(String::from(SOLVER_THREAD))
+            .spawn(move || {
+                let outcome = catch(source.clone(), || solve(puzzle, source, part, explain));
+                let _ = sender.send(outcome);
+            })
+            .expect("failed to spawn a solver thread");
+
+        self.running = Some(Running {
+            day: day.number,
+            started: Instant::now(),
+            receiver,
+        });
+        self.scroll = 0;
+    }
+
+    /// Picks up the outcome of the running day, if it has finished.
+    pub fn poll(&mut self) {
+        let Some(running) = &self.running else {
+            return;
+        };
+        let outcome = match running.receiver.try_recv() {
+            Ok(outcome) => outcome,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Outcome::Failed {
+                source: self.source.clone(),
+                message: String::from("the solver panicked"),
+            },
+        };
+
+        self.days[usize::from(running.day) - 1].last = Some(outcome);
+        self.running = None;
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] = split(
+            Direction::Vertical,
+            [Constraint::Min(0), Constraint::Length(1)],
+            frame.size(),
+        );
+        let [calendar, detail] = split(
+            Direction::Horizontal,
+            [Constraint::Length(24), Constraint::Min(0)],
+            main,
+        );
+        let [settings, results, explanation] = split(
+            Direction::Vertical,
+            [
+                Constraint::Length(6),
+                Constraint::Length(6),
+                Constraint::Min(0),
+            ],
+            detail,
+        );
+
+        let items: Vec<ListItem> = self
+            .days
+            .iter()
+            .map(|day| {
+                let status = match (&self.running, &day.last) {
+                    (Some(running), _) if running.day == day.number => "...",
+                    (_, Some(outcome)) => outcome.check_label(),
+                    _ => "",
+                };
+                let line = format!(
+                    "Day {:>2}  {:<2}  {}",
+                    day.number,
+                    "*".repeat(day.known),
+                    status
+                );
+                let style = match day.puzzle {
+                    Some(_) => Style::default(),
+                    None => Style::default().fg(Color::DarkGray),
+                };
+                ListItem::new(line).style(style)
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::default().borders(Borders::ALL).title("Calendar"))
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, calendar, &mut self.list);
+
+        let day = self.selected();
+        let part = match self.part {
+            Some(part) => part.to_string(),
+            None => String::from("both"),
+        };
+        let input = match &self.editing {
+            Some(path) => format!("path: {}_", path),
+            None => describe(&self.source),
+        };
+        let state = match (&self.running, day.puzzle) {
+            (Some(running), _) => format!(
+                "running day {} for {:.1?}",
+                running.day,
+                running.started.elapsed()
+            ),
+            (None, Some(_)) => String::from("ready"),
+            (None, None) => String::from("not implemented"),
+        };
+        let lines = vec![
+            Line::from(format!("Part     {}", part)),
+            Line::from(format!("Input    {}", input)),
+            Line::from(format!(
+                "Explain  {}",
+                if self.explain { "on" } else { "off" }
+            )),
+            Line::from(format!("Status   {}", state)),
+        ];
+        let title = format!("Day {}", day.number);
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)),
+            settings,
+        );
+
+        let (title, text, trace) = match &day.last {
+            Some(Outcome::Solved {
+                source,
+                record,
+                trace,
+            }) => (
+                format!("Results for {}", describe(source)),
+                report::render(std::slice::from_ref(record), Format::Table, true),
+                trace.join("\n"),
+            ),
+            Some(Outcome::Failed { source, message }) => (
+                format!("Results for {}", describe(source)),
+                format!("error: {}", message),
+                String::new(),
+            ),
+            None => (String::from("Results"), String::new(), String::new()),
+        };
+        frame.render_widget(
+            Paragraph::new(text).block(Block::default().borders(Borders::ALL).title(title)),
+            results,
+        );
+
+        let trace = match (trace.is_empty(), self.explain) {
+            (false, _) => trace,
+            (true, true) => String::from("Nothing explained yet, run the day."),
+            (true, false) => {
+                String::from("Press e, then run the day again, to see how it got its answers.")
+            }
+        };
+        frame.render_widget(
+            Paragraph::new(trace)
+                .scroll((self.scroll, 0))
+                .block(Block::default().borders(Borders::ALL).title("Explanation")),
+            explanation,
+        );
+
+        let keys = match self.editing {
+            Some(_) => "type a path to an input file  enter confirm  esc cancel",
+            None => "up/down day  p part  i input  c custom input  e explain  enter run  pgup/pgdn scroll  q quit",
+        };
+        frame.render_widget(Paragraph::new(keys), help);
+    }
+}
+
+fn split<const N: usize>(
+    direction: Direction,
+    constraints: [Constraint; N],
+    area: ratatui::layout::Rect,
+) -> [ratatui::layout::Rect; N] {
+    let areas = Layout::default()
+        .direction(direction)
+        .constraints(constraints)
+        .split(area);
+    std::array::from_fn(|index| areas[index])
+}
+
+fn describe(source: &InputSource) -> String {
+    match source {
+        InputSource::Embedded => String::from("puzzle input"),
+        InputSource::Stdin => String::from("stdin"),
+        InputSource::File(path) => path.display().to_string(),
+        InputSource::Example(name) => format!("example {}", name),
+    }
+}
+
+/// Runs `solve`, turning a panic into a failed run on `source`.
+fn catch(source: InputSource, solve: impl FnOnce() -> Outcome) -> Outcome {
+    panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or_else(|payload| Outcome::Failed {
+        source,
+        message: format!("the solver panicked: {}", panic_message(payload.as_ref())),
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        (None, None) => "unknown reason",
+    }
+}
+
+/// Solves a day like `aoc run --check` does, keeping what it explained apart
+/// from the answers.
+fn solve(
+    puzzle: &'static Puzzle,
+    source: InputSource,
+    part: Option<Part>,
+    explain: bool,
+) -> Outcome {
+    let solved = || -> Result<(Record, Vec<String>), String> {
+        let input = source.load(puzzle).map_err(|err| err.to_string())?;
+        let answers: Answers = source.answers(puzzle).map_err(|err| err.to_string())?;
+        // Like `run`, examples only solve the parts they have answers for.
+        let parts = match (part, &source) {
+            (Some(part), _) => vec![part],
+            (None, InputSource::Example(_)) => answers.parts(),
+            (None, _) => vec![Part::One, Part::Two],
+        };
+
+        let solve = || puzzle.solve(&input, &parts);
+        let run = match explain {
+            true => trace::capture(solve).0,
+            false => solve(),
+        }
+        .map_err(|err| err.to_string())?;
+
+        let mut trace = Vec::new();
+        let parts: Vec<PartRecord> = run
+            .parts
+            .into_iter()
+            .map(|solved| {
+                let mut part = PartRecord::new(solved, &answers, true);
+                if !part.trace.is_empty() {
+                    trace.push(format!("Part {}:", part.part));
+                    trace.extend(part.trace.drain(..).map(|line| format!("  {}", line)));
+                }
+                part
+            })
+            .collect();
+
+        let record = Record {
+            day: puzzle.day,
+            input_path: source.path(puzzle).display().to_string(),
+            parse_time: run.parse_time,
+            parts,
+            error: None,
+        };
+        Ok((record, trace))
+    };
+
+    match solved() {
+        Ok((record, trace)) => Outcome::Solved {
+            source,
+            record,
+            trace,
+        },
+        Err(message) => Outcome::Failed { source, message },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    use crossterm::event::KeyModifiers;
+    use ratatui::backend::TestBackend;
+
+    use super::*;
+
+    fn press(app: &mut App, code: KeyCode) -> bool {
+        app.handle(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    fn screen(app: &mut App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 40)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(usize::from(buffer.area.width))
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn wait(app: &mut App) {
+        let start = Instant::now();
+        while app.running.is_some() {
+            assert!(start.elapsed() < Duration::from_secs(10), "run timed out");
+            thread::sleep(Duration::from_millis(5));
+            app.poll();
+        }
+    }
+
+    #[test]
+    fn app_lists_whole_calendar() {
+        let mut app = App::new();
+        assert_eq!(app.days.len(), 25);
+        assert!(app.days[0].puzzle.is_some());
+        assert!(app.days[24].puzzle.is_none());
+
+        let screen = screen(&mut app);
+        assert!(screen.contains("Day  1"));
+        assert!(screen.contains("Part     both"));
+        assert!(screen.contains("Input    puzzle input"));
+        assert!(screen.contains("Status   ready"));
+    }
+
+    #[test]
+    fn app_moves_between_days() {
+        let mut app = App::new();
+        press(&mut app, KeyCode::Up);
+        assert_eq!(app.selected().number, 1);
+        press(&mut app, KeyCode::Down);
+        press(&mut app, KeyCode::Char('j'));
+        assert_eq!(app.selected().number, 3);
+        for _ in 0..30 {
+            press(&mut app, KeyCode::Down);
+        }
+        assert_eq!(app.selected().number, 25);
+    }
+
+    #[test]
+    fn app_cycles_parts_and_inputs() {
+        let mut app = App::new();
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.part, Some(Part::One));
+        press(&mut app, KeyCode::Char('p'));
+        press(&mut app, KeyCode::Char('p'));
+        assert_eq!(app.part, None);
+
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.source, InputSource::Example(String::from("1")));
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.source, InputSource::Example(String::from("2")));
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.source, InputSource::Embedded);
+
+        press(&mut app, KeyCode::Char('i'));
+        press(&mut app, KeyCode::Down);
+        assert_eq!(app.source, InputSource::Embedded);
+    }
+
+    #[test]
+    fn app_takes_custom_input_path() {
+        let mut app = App::new();
+        press(&mut app, KeyCode::Char('c'));
+        for c in "in.txx".chars() {
+            press(&mut app, KeyCode::Char(c));
+        }
+        press(&mut app, KeyCode::Backspace);
+        assert!(screen(&mut app).contains("path: in.tx_"));
+        // Keys are typed into the path rather than acting on the dashboard.
+        assert!(press(&mut app, KeyCode::Char('t')));
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.source, InputSource::File(PathBuf::from("in.txt")));
+
+        // The custom path comes back after the examples.
+        for _ in 0..3 {
+            press(&mut app, KeyCode::Char('i'));
+        }
+        assert_eq!(app.source, InputSource::Example(String::from("2")));
+        press(&mut app, KeyCode::Char('i'));
+        assert_eq!(app.source, InputSource::File(PathBuf::from("in.txt")));
+    }
+
+    #[test]
+    fn app_runs_example_with_explanation() {
+        let mut app = App::new();
+        press(&mut app, KeyCode::Char('i'));
+        press(&mut app, KeyCode::Char('e'));
+        press(&mut app, KeyCode::Enter);
+        wait(&mut app);
+
+        let Some(Outcome::Solved { record, trace, .. }) = &app.days[0].last else {
+            panic!("day 1 was not solved");
+        };
+        assert_eq!(record.parts.len(), 1);
+        assert_eq!(record.parts[0].check, Some(Status::Pass));
+        assert_eq!(trace.len(), 5);
+
+        let screen = screen(&mut app);
+        assert!(screen.contains("Results for example 1"));
+        assert!(screen.contains("142"));
+        assert!(screen.contains("Part 1:"));
+        assert!(screen.contains("line 1: \"1abc2\" starts with 1 and ends with 2, 12"));
+    }
+
+    #[test]
+    fn app_reports_failed_run() {
+        let mut app = App::new();
+        app.source = InputSource::File(PathBuf::from("does/not/exist.txt"));
+        press(&mut app, KeyCode::Char('r'));
+        wait(&mut app);
+
+        assert!(matches!(app.days[0].last, Some(Outcome::Failed { .. })));
+        assert!(screen(&mut app).contains("error: failed to read input file"));
+    }
+
+    #[test]
+    fn catch_reports_panic_as_failed_run() {
+        let source = InputSource::Example(String::from("1"));
+        let outcome = catch(source.clone(), || panic!("day {} exploded", 1));
+        let Outcome::Failed {
+            source: failed,
+            message,
+        } = outcome
+        else {
+            panic!("the panic was not caught");
+        };
+        assert_eq!(failed, source);
+        assert_eq!(message, "the solver panicked: day 1 exploded");
+    }
+
+    #[test]
+    fn panic_hook_restores_terminal_and_previous_hook() {
+        static RESTORED: AtomicBool = AtomicBool::new(false);
+        static PASSED_ON: AtomicUsize = AtomicUsize::new(0);
+
+        // Hooks are global, so only count the panics of this test's thread.
+        let name = thread::current().name().map(String::from);
+        panic::set_hook(Box::new(move |_| {
+            if thread::current().name() == name.as_deref() {
+                PASSED_ON.fetch_add(1, Ordering::SeqCst);
+            }
+        }));
+
+        drop(PanicHook::install(|| {
+            RESTORED.store(true, Ordering::SeqCst)
+        }));
+        let result = panic::catch_unwind(|| panic!("after the dashboard"));
+        assert!(result.is_err());
+        assert!(!RESTORED.load(Ordering::SeqCst));
+        assert_eq!(PASSED_ON.load(Ordering::SeqCst), 1);
+
+        let result = panic::catch_unwind(|| {
+            let _hook = PanicHook::install(|| RESTORED.store(true, Ordering::SeqCst));
+            panic!("the dashboard exploded");
+        });
+        assert!(result.is_err());
+        assert!(RESTORED.load(Ordering::SeqCst));
+        assert_eq!(PASSED_ON.load(Ordering::SeqCst), 2);
+
+        drop(panic::take_hook());
+    }
+
+    #[test]
+    fn app_quits() {
+        let mut app = App::new();
+        assert!(!press(&mut app, KeyCode::Char('q')));
+    }
+}
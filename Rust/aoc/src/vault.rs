@@ -0,0 +1,188 @@
+use std::io::{self, Read, Write};
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use age::armor::{ArmoredReader, ArmoredWriter, Format};
+use age::secrecy::ExposeSecret;
+use age::{x25519, Decryptor, Encryptor};
+use thiserror::Error;
+
+/// Name of the encrypted input committed in each day's directory.
+pub const ENCRYPTED_INPUT: &str = "input.txt.age";
+/// Name of the plaintext input, which is kept out of the repository.
+pub const PLAINTEXT_INPUT: &str = "input.txt";
+
+pub const KEY_ENV: &str = "AOC_KEY_FILE";
+
+const ARMOR_BEGIN: &str = "-----BEGIN AGE ENCRYPTED FILE-----";
+
+/// Location of the key file: `$AOC_KEY_FILE`, or `age-key.txt` at the root
+/// of the workspace.
+pub fn key_path() -> PathBuf {
+    match std::env::var_os(KEY_ENV) {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .expect("aoc is a workspace member")
+            .join("age-key.txt"),
+    }
+}
+
+/// Whether `input` is an ASCII-armored age file rather than a plaintext input.
+pub fn is_encrypted(input: &str) -> bool {
+    input.trim_start().starts_with(ARMOR_BEGIN)
+}
+
+/// An age X25519 identity, stored in the same format as `age-keygen` uses.
+pub struct Key {
+    identity: x25519::Identity,
+}
+
+impl Key {
+    pub fn generate() -> Self {
+        Key {
+            identity: x25519::Identity::generate(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, VaultError> {
+        let contents = std::fs::read_to_string(path).map_err(|source| VaultError::Key {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .find_map(|line| x25519::Identity::from_str(line).ok())
+            .map(|identity| Key { identity })
+            .ok_or_else(|| VaultError::InvalidKey {
+                path: path.to_path_buf(),
+            })
+    }
+
+    /// Writes the key, refusing to replace an existing one since that would
+    /// make every input encrypted with it unreadable.
+    pub fn save(&self, path: &Path) -> Result<(), VaultError> {
+        let contents = format!(
+            "# public key: {}\n{}\n",
+            self.public(),
+            self.identity.to_string().expose_secret()
+        );
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        options
+            .open(path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|source| VaultError::Key {
+                path: path.to_path_buf(),
+                source,
+            })
+    }
+
+    pub fn public(&self) -> String {
+        self.identity.to_public().to_string()
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        let recipient = Box::new(self.identity.to_public());
+        let encryptor = Encryptor::with_recipients(vec![recipient]).expect("one recipient");
+
+        let mut output = Vec::new();
+        let armor =
+            ArmoredWriter::wrap_output(&mut output, Format::AsciiArmor).expect("writing to memory");
+        let mut writer = encryptor.wrap_output(armor).expect("writing to memory");
+        writer
+            .write_all(plaintext.as_bytes())
+            .expect("writing to memory");
+        writer
+            .finish()
+            .and_then(|armor| armor.finish())
+            .expect("writing to memory");
+
+        String::from_utf8(output).expect("armor is ASCII")
+    }
+
+    pub fn decrypt(&self, armored: &str) -> Result<String, VaultError> {
+        let reader = ArmoredReader::new(armored.as_bytes());
+        let decryptor = match Decryptor::new(reader)? {
+            Decryptor::Recipients(decryptor) => decryptor,
+            Decryptor::Passphrase(_) => return Err(VaultError::Passphrase),
+        };
+
+        let mut plaintext = String::new();
+        decryptor
+            .decrypt(iter::once(&self.identity as &dyn age::Identity))?
+            .read_to_string(&mut plaintext)
+            .map_err(|source| VaultError::Read { source })?;
+        Ok(plaintext)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum VaultError {
+    #[error(
+        "failed to access key file {} (set {KEY_ENV} or run `aoc input keygen`): {source}",
+        path.display()
+    )]
+    Key { path: PathBuf, source: io::Error },
+    #[error("no age identity found in key file {}", path.display())]
+    InvalidKey { path: PathBuf },
+    #[error("failed to decrypt input: {0}")]
+    Decrypt(#[from] age::DecryptError),
+    #[error("failed to decrypt input: encrypted with a passphrase instead of a key")]
+    Passphrase,
+    #[error("failed to decrypt input: {source}")]
+    Read { source: io::Error },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypt_round_trips() {
+        let key = Key::generate();
+        let encrypted = key.encrypt("1abc2\npqr3stu8vwx\n");
+        assert!(is_encrypted(&encrypted));
+        assert!(!encrypted.contains("pqr3stu8vwx"));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), "1abc2\npqr3stu8vwx\n");
+    }
+
+    #[test]
+    fn decrypt_with_other_key() {
+        let encrypted = Key::generate().encrypt("secret");
+        let error = Key::generate().decrypt(&encrypted).unwrap_err();
+        assert!(matches!(error, VaultError::Decrypt(_)));
+    }
+
+    #[test]
+    fn plaintext_is_not_encrypted() {
+        assert!(!is_encrypted(""));
+        assert!(!is_encrypted("Time:      7  15   30\n"));
+    }
+
+    #[test]
+    fn key_file_round_trips() {
+        let path = std::env::temp_dir().join(format!("aoc-key-{}.txt", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let key = Key::generate();
+        key.save(&path).unwrap();
+        assert!(matches!(
+            Key::generate().save(&path),
+            Err(VaultError::Key { .. })
+        ));
+
+        let loaded = Key::load(&path).unwrap();
+        assert_eq!(loaded.public(), key.public());
+        assert_eq!(loaded.decrypt(&key.encrypt("input")).unwrap(), "input");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,273 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::time::Duration;
+
+use aoc_mock::{Config, MockServer, FIXTURES};
+
+const SESSION: &str = "test-session";
+
+fn server(cooldown: Duration) -> MockServer {
+    let config = Config::load(Path::new(FIXTURES), SESSION).unwrap();
+    MockServer::start("127.0.0.1:0", Config { cooldown, ..config }).unwrap()
+}
+
+fn aoc(server: &MockServer, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(args)
+        .args(["--base-url", &server.url()])
+        .env("AOC_SESSION", SESSION)
+        .output()
+        .unwrap()
+}
+
+/// Runs `aoc submit`, logging submissions to `history` instead of the day's
+/// directory.
+fn submit(server: &MockServer, history: &Path, args: &[&str]) -> Output {
+    let mut args = [&["submit"], args].concat();
+    args.extend(["--history", history.to_str().unwrap()]);
+    aoc(server, &args)
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-client-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+#[test]
+fn fetch_input_to_file() {
+    let server = server(Duration::ZERO);
+    let path = temp_path("fetch.txt");
+
+    let output = aoc(
+        &server,
+        &["fetch", "--day", "2", "--output", path.to_str().unwrap()],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        std::fs::read_to_string(&path).unwrap(),
+        std::fs::read_to_string(Path::new(FIXTURES).join("day-02/input.txt")).unwrap()
+    );
+
+    let output = aoc(
+        &server,
+        &["fetch", "--day", "2", "--output", path.to_str().unwrap()],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("pass --force to overwrite it"));
+
+    let output = aoc(
+        &server,
+        &[
+            "fetch",
+            "--day",
+            "2",
+            "--output",
+            path.to_str().unwrap(),
+            "--force",
+        ],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn fetch_input_to_stdout() {
+    let server = server(Duration::ZERO);
+    let output = aoc(&server, &["fetch", "--day", "1", "--output", "-"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).starts_with("1abc2\n"));
+}
+
+#[test]
+fn fetch_without_session() {
+    let server = server(Duration::ZERO);
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "fetch",
+            "--day",
+            "1",
+            "--output",
+            "-",
+            "--base-url",
+            &server.url(),
+        ])
+        .env("AOC_SESSION", "someone-else")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("request failed with status 400: Puzzle inputs differ by user.")
+    );
+}
+
+#[test]
+fn fetch_unknown_day() {
+    let server = server(Duration::ZERO);
+    let output = aoc(&server, &["fetch", "--day", "25", "--output", "-"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("request failed with status 404"));
+}
+
+#[test]
+fn submit_answers() {
+    let history = temp_path("submit_answers.toml");
+    let server = server(Duration::ZERO);
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "2", "--answer", "2286"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("already solved or part locked"));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "9"],
+    );
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("9 is incorrect, too high"));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "7"],
+    );
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("7 is incorrect, too low"));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "eight"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("answer was not accepted: incorrect"));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "8"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "day 2 part 1: 8 is correct\n");
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "2", "--answer", "2286"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+}
+
+#[test]
+fn submit_is_rate_limited() {
+    let history = temp_path("submit_is_rate_limited.toml");
+    let server = server(Duration::from_secs(60));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "1", "--part", "1", "--answer", "1"],
+    );
+    assert!(stdout(&output).contains("incorrect, too low"));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "1", "--part", "1", "--answer", "142"],
+    );
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("142 is rate limited, 60s left to wait"));
+
+    // Only the verdict that said something about the answer is kept.
+    let log = std::fs::read_to_string(&history).unwrap();
+    assert_eq!(log.matches("[[submission]]").count(), 1);
+}
+
+#[test]
+fn submit_solved_answer() {
+    let history = temp_path("submit_solved_answer.toml");
+    let server = server(Duration::ZERO);
+
+    let example = Path::new(FIXTURES).join("day-01/input.txt");
+    let output = submit(
+        &server,
+        &history,
+        &[
+            "--day",
+            "1",
+            "--part",
+            "1",
+            "--input",
+            example.to_str().unwrap(),
+        ],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "day 1 part 1: 142 is correct\n");
+}
+
+#[test]
+fn submit_is_guarded_by_history() {
+    let history = temp_path("submit_is_guarded_by_history.toml");
+    let server = server(Duration::ZERO);
+
+    submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "10"],
+    );
+    submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "3"],
+    );
+
+    for (answer, reason) in [
+        ("10", "it is already submitted and found too high"),
+        ("12", "it is not below 10, which was too high"),
+        ("2", "it is not above 3, which was too low"),
+    ] {
+        let output = submit(
+            &server,
+            &history,
+            &["--day", "2", "--part", "1", "--answer", answer],
+        );
+        assert!(!output.status.success());
+        assert!(stdout(&output).is_empty(), "{}", stdout(&output));
+        assert!(stderr(&output).contains(reason), "{}", stderr(&output));
+    }
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "12", "--force"],
+    );
+    assert!(stderr(&output).contains("warning: 12 is not below 10, which was too high"));
+    assert!(stdout(&output).contains("12 is incorrect, too high"));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "8"],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let output = submit(
+        &server,
+        &history,
+        &["--day", "2", "--part", "1", "--answer", "8"],
+    );
+    assert!(stderr(&output).contains("refusing to submit 8, it is already solved with 8"));
+}
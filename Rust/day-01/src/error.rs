@@ -0,0 +1,7 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveError {
+    #[error("line {line}: no calibration digits found")]
+    NoDigits { line: usize },
+}
@@ -0,0 +1,50 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// A calibration document of `size` lines mixing letters, digits and
+/// spelled-out digits, with at least one digit on every line.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    (0..size).map(|_| line(rng)).collect::<Vec<_>>().join("\n")
+}
+
+fn line(rng: &mut dyn RngCore) -> String {
+    let len = rng.gen_range(4..=40);
+    let mut line = String::new();
+
+    while line.len() < len {
+        match rng.gen_range(0..10) {
+            0 | 1 => line.push(digit(rng)),
+            2 | 3 => line.push_str(WORDS.choose(rng).unwrap()),
+            _ => line.push(rng.gen_range('a'..='z')),
+        }
+    }
+
+    if !line.chars().any(|c| c.is_ascii_digit()) {
+        let at = rng.gen_range(0..=line.len());
+        line.insert(at, digit(rng));
+    }
+
+    line
+}
+
+fn digit(rng: &mut dyn RngCore) -> char {
+    rng.gen_range('1'..='9')
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn generate_writes_size_lines() {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input.lines().count(), 50);
+    }
+}
@@ -0,0 +1,6 @@
+/// Splits the calibration document into its lines. Each part reads the
+/// calibration value out of a line differently, so there is nothing more to
+/// parse up front.
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
@@ -1,36 +1,53 @@
-pub fn process(input: &str) -> String {
-    parse_input(input).iter().sum::<u32>().to_string()
+use aoc_core::{trace, Answer};
+
+use crate::SolveError;
+
+pub fn process(lines: &[&str]) -> Result<Answer, SolveError> {
+    Ok(calibration_values(lines)?.iter().sum::<u32>().into())
 }
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input.lines().map(parse_line).collect()
+fn calibration_values(lines: &[&str]) -> Result<Vec<u32>, SolveError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let value = parse_line(line).ok_or(SolveError::NoDigits { line: index + 1 })?;
+            trace!(
+                "line {}: {:?} starts with {} and ends with {}, {}",
+                index + 1,
+                line,
+                value / 10,
+                value % 10,
+                value
+            );
+            Ok(value)
+        })
+        .collect()
 }
 
-fn parse_line(line: &str) -> u32 {
-    let mut values = line.chars().filter(|c| c.is_ascii_digit());
-    let first = values.next().expect("number should be present");
-    let last = values.last().unwrap_or(first);
-    format!("{}{}", first, last).parse().unwrap()
+fn parse_line(line: &str) -> Option<u32> {
+    let mut values = line.chars().filter_map(|c| c.to_digit(10));
+    let first = values.next()?;
+    let last = values.next_back().unwrap_or(first);
+    Some(first * 10 + last)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::parse_input;
 
-    const INPUT: &str = "1abc2
-pqr3stu8vwx
-a1b2c3d4e5f
-treb7uchet";
+    const INPUT: &str = include_str!("../examples/1.txt");
 
     #[test]
-    fn parse_input_with_example() {
-        let calibration_values = parse_input(INPUT);
+    fn calibration_values_with_example() {
+        let calibration_values = calibration_values(&parse_input(INPUT)).unwrap();
         assert_eq!(calibration_values, vec![12, 38, 15, 77])
     }
 
     #[test]
-    fn process_with_example() {
-        let result = process(INPUT);
-        assert_eq!(result, "142");
+    fn process_with_line_without_digits() {
+        let result = process(&["1abc2", "pqrstu"]);
+        assert_eq!(result, Err(SolveError::NoDigits { line: 2 }));
     }
 }
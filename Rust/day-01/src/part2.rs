@@ -1,12 +1,31 @@
-pub fn process(input: &str) -> String {
-    parse_input(input).iter().sum::<u32>().to_string()
+use aoc_core::{trace, Answer};
+
+use crate::SolveError;
+
+pub fn process(lines: &[&str]) -> Result<Answer, SolveError> {
+    Ok(calibration_values(lines)?.iter().sum::<u32>().into())
 }
 
-fn parse_input(input: &str) -> Vec<u32> {
-    input.lines().map(parse_line).collect()
+fn calibration_values(lines: &[&str]) -> Result<Vec<u32>, SolveError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let value = parse_line(line).ok_or(SolveError::NoDigits { line: index + 1 })?;
+            trace!(
+                "line {}: {:?} starts with {} and ends with {}, {}",
+                index + 1,
+                line,
+                value / 10,
+                value % 10,
+                value
+            );
+            Ok(value)
+        })
+        .collect()
 }
 
-fn parse_line(line: &str) -> u32 {
+fn parse_line(line: &str) -> Option<u32> {
     let mut values = line.char_indices().filter_map(|(index, c)| {
         if c.is_ascii_digit() {
             return c.to_digit(10);
@@ -16,9 +35,9 @@ fn parse_line(line: &str) -> u32 {
         }
         None
     });
-    let first = values.next().expect("number should be present");
-    let last = values.last().unwrap_or(first);
-    format!("{}{}", first, last).parse().unwrap()
+    let first = values.next()?;
+    let last = values.next_back().unwrap_or(first);
+    Some(first * 10 + last)
 }
 
 fn parse_spelled_digit(input: &str) -> Option<u32> {
@@ -46,14 +65,9 @@ mod tests {
     use rstest::rstest;
 
     use super::*;
+    use crate::parser::parse_input;
 
-    const INPUT: &str = "two1nine
-eightwothree
-abcone2threexyz
-xtwone3four
-4nineeightseven2
-zoneight234
-7pqrstsixteen";
+    const INPUT: &str = include_str!("../examples/2.txt");
 
     #[rstest]
     #[case("two1nine", 29)]
@@ -66,18 +80,22 @@ zoneight234
     #[case("treb7uchet", 77)]
     fn parse_line_with_examples(#[case] input: &str, #[case] expected: u32) {
         let result = parse_line(input);
-        assert_eq!(expected, result);
+        assert_eq!(Some(expected), result);
     }
 
     #[test]
-    fn parse_input_with_example() {
-        let calibration_values = parse_input(INPUT);
+    fn calibration_values_with_example() {
+        let calibration_values = calibration_values(&parse_input(INPUT)).unwrap();
         assert_eq!(calibration_values, vec![29, 83, 13, 24, 42, 14, 76])
     }
 
     #[test]
-    fn process_with_example() {
-        let result = process(INPUT);
-        assert_eq!(result, "281");
+    fn calibration_values_explains_each_line() {
+        let (_, lines) = trace::capture(|| calibration_values(&parse_input(INPUT)));
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[1],
+            "line 2: \"eightwothree\" starts with 8 and ends with 3, 83"
+        );
     }
 }
@@ -0,0 +1,61 @@
+//! Looks for a digit, or for part 2 a spelled-out one, at every position of
+//! each line.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(sum(input, |rest| rest.chars().next()?.to_digit(10)))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(sum(input, |rest| {
+            let spelled = (1..).zip(WORDS).find(|(_, word)| rest.starts_with(word));
+            rest.chars()
+                .next()?
+                .to_digit(10)
+                .or(spelled.map(|(digit, _)| digit))
+        }))
+    }
+}
+
+/// Sums the first and last digit of each line, trying `digit` at every
+/// position of the line.
+fn sum(input: &str, digit: impl Fn(&str) -> Option<u32>) -> Answer {
+    let mut total = 0;
+    for line in input.lines() {
+        let digits: Vec<u32> = (0..line.len()).filter_map(|i| digit(&line[i..])).collect();
+        total += digits[0] * 10 + digits[digits.len() - 1];
+    }
+    total.into()
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..50usize,
+    cases: 256,
+);
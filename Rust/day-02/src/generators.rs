@@ -0,0 +1,39 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+const COLORS: [&str; 3] = ["red", "green", "blue"];
+
+/// A record of `size` games, each with one to six draws of up to twenty
+/// cubes of a color.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let draws: Vec<String> = (0..rng.gen_range(1..=6)).map(|_| draw(rng)).collect();
+            format!("Game {}: {}", id, draws.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn draw(rng: &mut dyn RngCore) -> String {
+    let count = rng.gen_range(1..=COLORS.len());
+    COLORS
+        .choose_multiple(rng, count)
+        .map(|color| format!("{} {}", rng.gen_range(1..=20), color))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn generate_writes_size_lines() {
+        let input = generate(&mut StdRng::seed_from_u64(1), 50);
+        assert_eq!(input.lines().count(), 50);
+    }
+}
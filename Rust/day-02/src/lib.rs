@@ -1,2 +1,43 @@
+use aoc_core::{Answer, Solver};
+use model::Game;
+use rand::RngCore;
+
+pub mod error;
+pub mod generators;
+pub mod model;
+pub mod parser;
 pub mod part1;
 pub mod part2;
+#[cfg(any(test, feature = "reference"))]
+pub mod reference;
+
+pub use error::SolveError;
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 2;
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+    const INPUT: &'static str = include_str!("../input.txt.age");
+
+    type Input<'a> = Vec<Game>;
+    type Error = SolveError;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        parser::parse_input(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part1::process(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        part2::process(input)
+    }
+
+    fn generate(rng: &mut dyn RngCore, size: usize) -> Option<String> {
+        Some(generators::generate(rng, size))
+    }
+}
+
+aoc_core::example_tests!(crate::Solution);
@@ -0,0 +1,22 @@
+#[derive(Debug, PartialEq)]
+pub struct Round(pub u32, pub u32, pub u32);
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Round>,
+}
+
+impl Game {
+    /// The most cubes of each colour shown in any one round, the fewest the
+    /// bag could have held.
+    pub fn maximum(&self) -> Round {
+        self.rounds.iter().fold(Round(0, 0, 0), |maximum, round| {
+            Round(
+                maximum.0.max(round.0),
+                maximum.1.max(round.1),
+                maximum.2.max(round.2),
+            )
+        })
+    }
+}
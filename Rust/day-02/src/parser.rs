@@ -0,0 +1,99 @@
+use aoc_parse::{finish, labelled, lines, number, IResult};
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::combinator::{cut, value};
+use nom::error::context;
+use nom::multi::separated_list0;
+
+use crate::model::{Game, Round};
+use crate::SolveError;
+
+#[derive(Debug, Clone)]
+enum Colors {
+    Red,
+    Green,
+    Blue,
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Game>, SolveError> {
+    Ok(finish(input, lines(parse_game))?)
+}
+
+fn parse_game(input: &str) -> IResult<'_, Game> {
+    let (input, id) = labelled("Game", number)(input)?;
+    let (input, rounds) = labelled(":", separated_list0(tag("; "), parse_round))(input)?;
+
+    Ok((input, Game { id, rounds }))
+}
+
+fn parse_round(input: &str) -> IResult<'_, Round> {
+    let mut round = Round(0, 0, 0);
+    let (input, values) = separated_list0(tag(", "), parse_round_value)(input)?;
+
+    for (color, amount) in values {
+        match color {
+            Colors::Red => round.0 = amount,
+            Colors::Green => round.1 = amount,
+            Colors::Blue => round.2 = amount,
+        }
+    }
+
+    Ok((input, round))
+}
+
+fn parse_round_value(input: &str) -> IResult<'_, (Colors, u32)> {
+    let (input, amount) = number(input)?;
+    let (input, _) = tag(" ")(input)?;
+    // Past the count a colour must follow, so report it here rather than
+    // backtracking to the separator before the count.
+    let (input, color) = cut(context(
+        "red, green or blue",
+        alt((
+            value(Colors::Red, tag("red")),
+            value(Colors::Green, tag("green")),
+            value(Colors::Blue, tag("blue")),
+        )),
+    ))(input)?;
+
+    Ok((input, (color, amount)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", Game{id: 1, rounds: vec![Round(4, 0, 3), Round(1, 2, 6), Round(0, 2, 0)]})]
+    #[case("Game 73: 1 green, 1 red, 10 blue; 12 blue; 2 red, 9 blue", Game{id: 73, rounds: vec![Round(1, 1, 10), Round(0, 0, 12), Round(2, 0, 9)]})]
+    fn parse_game_with_examples(#[case] input: &str, #[case] expected: Game) {
+        let (input, result) = parse_game(input).unwrap();
+        assert_eq!(input, "");
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(
+        "Game 1: 3 blue, 4 red\nGame 2: 1 blue, 2 purple",
+        "line 2, column 19: expected red, green or blue near \"purple\""
+    )]
+    #[case(
+        "Game 1: 3 blue, 4 red\nGame 2: 1 purple; 2 red",
+        "line 2, column 11: expected red, green or blue near \"purple; 2 red\""
+    )]
+    fn parse_input_with_unknown_color(#[case] input: &str, #[case] expected: &str) {
+        let error = parse_input(input).unwrap_err().to_string();
+        assert_eq!(error, format!("invalid input at {}", expected));
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(format!("example_{}", name), parse_input(&input).unwrap());
+    }
+}
@@ -1,51 +1,44 @@
-use crate::part1::parse_input;
+use aoc_core::{trace, Answer};
 
-pub fn process(input: &str) -> String {
-    let (_, games) = parse_input(input).expect("should parse input");
+use crate::model::{Game, Round};
+use crate::SolveError;
 
-    games
+pub fn process(games: &[Game]) -> Result<Answer, SolveError> {
+    let result = games
         .iter()
         .map(|game| {
-            let mut rounds = game.rounds.iter();
-            let first = rounds.next().expect("should have at least one round");
-            let mut red = first.0;
-            let mut green = first.1;
-            let mut blue = first.2;
-
-            for round in rounds {
-                if round.0 > red {
-                    red = round.0;
-                }
-                if round.1 > green {
-                    green = round.1;
-                }
-                if round.2 > blue {
-                    blue = round.2;
-                }
-            }
-
-            [red, green, blue]
-                .iter()
-                .filter(|v| **v > 0)
-                .product::<u32>()
+            let Round(red, green, blue) = game.maximum();
+            let power = u128::from(red) * u128::from(green) * u128::from(blue);
+            trace!(
+                "game {}: at most {} red, {} green and {} blue, power {}",
+                game.id,
+                red,
+                green,
+                blue,
+                power
+            );
+            power
         })
-        .sum::<u32>()
-        .to_string()
+        .sum::<u128>();
+
+    Ok(result.into())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::trace;
 
-    const INPUT: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+    use super::*;
+    use crate::parser::parse_input;
 
     #[test]
-    fn process_with_example_input() {
-        let result = process(INPUT);
-        assert_eq!(result, "2286");
+    fn process_explains_each_game() {
+        let games = parse_input(include_str!("../examples/1.txt")).unwrap();
+        let (_, lines) = trace::capture(|| process(&games));
+        assert_eq!(lines.len(), 5);
+        assert_eq!(
+            lines[0],
+            "game 1: at most 4 red, 2 green and 6 blue, power 48"
+        );
     }
 }
@@ -0,0 +1,85 @@
+//! Splits each game into loose handfuls of cubes and checks them one by one.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let mut total = 0;
+        for (id, cubes) in games(input) {
+            let possible = cubes.iter().all(|&(count, color)| match color {
+                "red" => count <= 12,
+                "green" => count <= 13,
+                _ => count <= 14,
+            });
+            if possible {
+                total += id;
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let mut total = 0;
+        for (_, cubes) in games(input) {
+            let mut power = 1;
+            for color in ["red", "green", "blue"] {
+                let fewest = cubes
+                    .iter()
+                    .filter(|(_, other)| *other == color)
+                    .map(|(count, _)| *count)
+                    .max()
+                    .unwrap_or(0);
+                power *= fewest;
+            }
+            total += power;
+        }
+        Ok(total.into())
+    }
+}
+
+/// Each game's id with every handful of cubes shown in it, regardless of
+/// which draw it was in.
+fn games(input: &str) -> Vec<(u32, Vec<(u32, &str)>)> {
+    input
+        .lines()
+        .map(|line| {
+            let (game, draws) = line.split_once(": ").unwrap();
+            let id = game.trim_start_matches("Game ").parse().unwrap();
+            let cubes = draws
+                .split("; ")
+                .flat_map(|draw| draw.split(", "))
+                .map(|cubes| {
+                    let (count, color) = cubes.split_once(' ').unwrap();
+                    (count.parse().unwrap(), color)
+                })
+                .collect();
+            (id, cubes)
+        })
+        .collect()
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..50usize,
+    cases: 256,
+);
@@ -0,0 +1,14 @@
+use aoc_grid::GridError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveError {
+    #[error("invalid schematic: {0}")]
+    Grid(#[from] GridError),
+    #[error("line {line}, column {column}: invalid number {digits:?}")]
+    InvalidNumber {
+        line: usize,
+        column: usize,
+        digits: String,
+    },
+}
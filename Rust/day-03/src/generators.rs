@@ -0,0 +1,57 @@
+use rand::{Rng, RngCore};
+
+const SYMBOLS: &[u8] = b"*#+$/@=%-&";
+
+/// A `size` by `size` engine schematic with numbers of up to three digits
+/// and symbols scattered over it. Gears get a larger share of the symbols so
+/// that some of them end up next to exactly two numbers.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    (0..size)
+        .map(|_| row(rng, size))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn row(rng: &mut dyn RngCore, size: usize) -> String {
+    let mut row = vec![b'.'; size];
+    let mut col = 0;
+
+    while col < size {
+        match rng.gen_range(0..20) {
+            0..=2 => {
+                let len = rng.gen_range(1..=3).min(size - col);
+                row[col] = rng.gen_range(b'1'..=b'9');
+                for cell in &mut row[col + 1..col + len] {
+                    *cell = rng.gen_range(b'0'..=b'9');
+                }
+                // Leave a gap so the next number doesn't run into this one.
+                col += len + 1;
+            }
+            3 => {
+                row[col] = b'*';
+                col += 1;
+            }
+            4 => {
+                row[col] = SYMBOLS[rng.gen_range(0..SYMBOLS.len())];
+                col += 1;
+            }
+            _ => col += 1,
+        }
+    }
+
+    String::from_utf8(row).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn generate_writes_size_columns() {
+        let input = generate(&mut StdRng::seed_from_u64(1), 40);
+        assert!(input.lines().all(|line| line.len() == 40));
+    }
+}
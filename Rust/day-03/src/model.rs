@@ -0,0 +1,29 @@
+use aoc_grid::{Grid, Position};
+
+/// A number in the engine schematic, located by the position of its first digit.
+#[derive(Debug, PartialEq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub col: usize,
+    pub len: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<Number>,
+}
+
+impl Schematic {
+    /// Positions and values of the cells surrounding a number, including the
+    /// number's own digits, in row-major order.
+    pub fn neighbours(&self, number: &Number) -> impl Iterator<Item = (Position, char)> + '_ {
+        let rows = number.row.saturating_sub(1)..number.row + 2;
+        let cols = number.col.saturating_sub(1)..number.col + number.len + 1;
+
+        self.grid
+            .region(rows, cols)
+            .map(|(position, value)| (position, *value))
+    }
+}
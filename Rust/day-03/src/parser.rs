@@ -0,0 +1,108 @@
+use aoc_grid::Grid;
+
+use crate::model::{Number, Schematic};
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Schematic, SolveError> {
+    let grid: Grid<char> = input.parse()?;
+    let mut numbers = Vec::new();
+
+    for (row, values) in grid.rows().enumerate() {
+        let mut col = 0;
+
+        while col < values.len() {
+            if !values[col].is_ascii_digit() {
+                col += 1;
+                continue;
+            }
+
+            // Read all the number digits and skip past them.
+            let digits = read_digits(&values[col..]);
+            let value = digits.parse().map_err(|_| SolveError::InvalidNumber {
+                line: row + 1,
+                column: col + 1,
+                digits: digits.clone(),
+            })?;
+            numbers.push(Number {
+                value,
+                row,
+                col,
+                len: digits.len(),
+            });
+            col += digits.len();
+        }
+    }
+
+    Ok(Schematic { grid, numbers })
+}
+
+fn read_digits(values: &[char]) -> String {
+    values
+        .iter()
+        .take_while(|value| value.is_ascii_digit())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn parse_input_finds_numbers() {
+        let schematic = parse_input("467..114..\n...*......").unwrap();
+        assert_eq!(
+            schematic.numbers,
+            vec![
+                Number {
+                    value: 467,
+                    row: 0,
+                    col: 0,
+                    len: 3
+                },
+                Number {
+                    value: 114,
+                    row: 0,
+                    col: 5,
+                    len: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_input_with_number_too_large() {
+        let result = parse_input("..............\n.99999999999*.\n..............");
+        assert_eq!(
+            result,
+            Err(SolveError::InvalidNumber {
+                line: 2,
+                column: 2,
+                digits: String::from("99999999999")
+            })
+        );
+    }
+
+    #[test]
+    fn parse_input_with_ragged_lines() {
+        let result = parse_input("467..114..\n...*..");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "invalid schematic: line 2 has 6 cells but the first one has 10"
+        );
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(
+            format!("example_{}", name),
+            parse_input(&input).unwrap().numbers
+        );
+    }
+}
@@ -1,99 +1,59 @@
-use itertools::Itertools;
-
-pub fn process(input: &str) -> String {
-    let matrix: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
-    let mut part_numbers: Vec<u32> = Vec::new();
-
-    for (row, values) in matrix.iter().enumerate() {
-        let mut row_iter = values.iter().enumerate();
-
-        while let Some((col, element)) = row_iter.next() {
-            // Check if current character is a ASCII digit.
-            if element.is_ascii_digit() {
-                // Read all the number digits.
-                let digits = read_digits(&values[col..], *element);
-                for _ in 0..digits.len() {
-                    row_iter.next();
-                }
-
-                if is_part_number(&matrix, &digits, (row, col)) {
-                    let part_number = digits.parse().expect("should be a valid number");
-                    part_numbers.push(part_number);
-                }
+use aoc_core::{trace, Answer};
+
+use crate::model::Schematic;
+use crate::SolveError;
+
+pub fn process(schematic: &Schematic) -> Result<Answer, SolveError> {
+    // A part number is any number with a symbol, other than '.' or an
+    // ASCII digit, in one of its surrounding positions.
+    let result = schematic
+        .numbers
+        .iter()
+        .filter(|number| {
+            let symbol = schematic
+                .neighbours(number)
+                .find(|(_, value)| !value.is_ascii_digit() && *value != '.');
+            match symbol {
+                Some(((row, col), value)) => trace!(
+                    "{} at line {}, column {} is a part next to {:?} at line {}, column {}",
+                    number.value,
+                    number.row + 1,
+                    number.col + 1,
+                    value,
+                    row + 1,
+                    col + 1
+                ),
+                None => trace!(
+                    "{} at line {}, column {} is not next to a symbol",
+                    number.value,
+                    number.row + 1,
+                    number.col + 1
+                ),
             }
-        }
-    }
+            symbol.is_some()
+        })
+        .map(|number| u64::from(number.value))
+        .sum::<u64>();
 
-    part_numbers.iter().sum::<u32>().to_string()
-}
-
-pub fn read_digits(values: &[char], element: char) -> String {
-    let mut digits = String::new();
-    digits.push(element);
-
-    // Collect the remaining digits of the number and advance the
-    // iterator if more digits are found.
-    loop {
-        let offset = digits.len();
-        match values.get(offset) {
-            Some(value) => {
-                if !value.is_ascii_digit() {
-                    break;
-                }
-                digits.push(*value);
-            }
-            None => break,
-        }
-    }
-    digits
-}
-
-fn is_part_number(matrix: &[Vec<char>], digits: &str, (row, col): (usize, usize)) -> bool {
-    // Check surrounding positions in the matrix for any symbol
-    // different from '.' and an ASCII digit.
-    let col_range = if col == 0 {
-        0..=digits.len()
-    } else {
-        col - 1..=(col + digits.len())
-    };
-
-    let row_range = if row == 0 {
-        0..=row + 1
-    } else {
-        row - 1..=(row + 1)
-    };
-
-    for (r, c) in row_range.cartesian_product(col_range) {
-        if let Some(row) = matrix.get(r) {
-            if let Some(value) = row.get(c) {
-                if !value.is_ascii_digit() && *value != '.' {
-                    return true;
-                }
-            }
-        }
-    }
-
-    false
+    Ok(result.into())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::trace;
 
-    const INPUT: &str = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
+    use super::*;
+    use crate::parser::parse_input;
 
     #[test]
-    fn process_with_example_input() {
-        let result = process(INPUT);
-        assert_eq!(result, "4361");
+    fn process_explains_each_number() {
+        let schematic = parse_input(include_str!("../examples/1.txt")).unwrap();
+        let (_, lines) = trace::capture(|| process(&schematic));
+        assert_eq!(lines.len(), schematic.numbers.len());
+        assert_eq!(
+            lines[0],
+            "467 at line 1, column 1 is a part next to '*' at line 2, column 4"
+        );
+        assert_eq!(lines[1], "114 at line 1, column 6 is not next to a symbol");
     }
 }
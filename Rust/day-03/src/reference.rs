@@ -0,0 +1,114 @@
+//! Finds the numbers on the grid and compares each of them against every cell.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+
+        let mut total = 0;
+        for number in numbers(&grid) {
+            let mut is_part = false;
+            for (row, line) in grid.iter().enumerate() {
+                for (col, &cell) in line.iter().enumerate() {
+                    if number.touches(row, col) && cell != b'.' && !cell.is_ascii_digit() {
+                        is_part = true;
+                    }
+                }
+            }
+            if is_part {
+                total += number.value;
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let grid: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let numbers = numbers(&grid);
+
+        let mut total = 0;
+        for (row, line) in grid.iter().enumerate() {
+            for (col, &cell) in line.iter().enumerate() {
+                if cell != b'*' {
+                    continue;
+                }
+                let parts: Vec<u64> = numbers
+                    .iter()
+                    .filter(|number| number.touches(row, col))
+                    .map(|number| number.value)
+                    .collect();
+                if parts.len() == 2 {
+                    total += parts[0] * parts[1];
+                }
+            }
+        }
+        Ok(total.into())
+    }
+}
+
+struct Number {
+    value: u64,
+    row: usize,
+    start: usize,
+    end: usize,
+}
+
+impl Number {
+    /// Whether the cell at `row`, `col` is next to the number, diagonally
+    /// included.
+    fn touches(&self, row: usize, col: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && col + 1 >= self.start && col <= self.end
+    }
+}
+
+fn numbers(grid: &[&[u8]]) -> Vec<Number> {
+    let mut numbers = Vec::new();
+    for (row, line) in grid.iter().enumerate() {
+        let mut col = 0;
+        while col < line.len() {
+            let start = col;
+            while col < line.len() && line[col].is_ascii_digit() {
+                col += 1;
+            }
+            if col > start {
+                let digits = std::str::from_utf8(&line[start..col]).unwrap();
+                numbers.push(Number {
+                    value: digits.parse().unwrap(),
+                    row,
+                    start,
+                    end: col,
+                });
+            } else {
+                col += 1;
+            }
+        }
+    }
+    numbers
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..40usize,
+    cases: 64,
+);
@@ -0,0 +1,10 @@
+use aoc_parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveError {
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    #[error("answer does not fit in a u64")]
+    Overflow,
+}
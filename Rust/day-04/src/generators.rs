@@ -0,0 +1,85 @@
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, RngCore};
+
+const WINNERS: usize = 10;
+const NUMBERS: usize = 25;
+const MAX_NUMBER: u32 = 99;
+
+/// A pile of `size` scratchcards with ten winning numbers and twenty-five
+/// numbers each, all between 1 and 99.
+///
+/// Cards match fewer than one number on average, which keeps the number of
+/// copies won in part 2 from growing exponentially with the pile, and never
+/// win copies of cards past the end of the pile.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let width = size.to_string().len();
+
+    (1..=size)
+        .map(|id| {
+            let matches = match rng.gen_range(0..20) {
+                0..=11 => 0,
+                12..=16 => 1,
+                17 | 18 => 2,
+                _ => rng.gen_range(3..=WINNERS),
+            };
+            let (winners, numbers) = card(rng, matches.min(size - id));
+
+            format!(
+                "Card {:>width$}: {} | {}",
+                id,
+                join(&winners),
+                join(&numbers)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Winning numbers and numbers of a card where `matches` of the numbers win.
+fn card(rng: &mut dyn RngCore, matches: usize) -> (Vec<u32>, Vec<u32>) {
+    let mut pool: Vec<u32> = index::sample(rng, MAX_NUMBER as usize, MAX_NUMBER as usize)
+        .into_iter()
+        .map(|index| index as u32 + 1)
+        .collect();
+    let losers = pool.split_off(WINNERS);
+    let winners = pool;
+
+    let mut numbers: Vec<u32> = winners[..matches]
+        .iter()
+        .chain(&losers[..NUMBERS - matches])
+        .copied()
+        .collect();
+    numbers.shuffle(rng);
+
+    (winners, numbers)
+}
+
+fn join(numbers: &[u32]) -> String {
+    numbers
+        .iter()
+        .map(|number| format!("{:>2}", number))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn generate_writes_size_lines() {
+        let input = generate(&mut StdRng::seed_from_u64(1), 200);
+        assert_eq!(input.lines().count(), 200);
+    }
+
+    #[test]
+    fn cards_match_the_requested_numbers() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let (winners, numbers) = card(&mut rng, 3);
+        assert_eq!((winners.len(), numbers.len()), (WINNERS, NUMBERS));
+        assert_eq!(numbers.iter().filter(|n| winners.contains(n)).count(), 3);
+    }
+}
@@ -0,0 +1,19 @@
+use crate::SolveError;
+
+#[derive(Debug, PartialEq)]
+pub struct Card {
+    pub id: u32,
+    pub winners: Vec<u32>,
+    pub numbers: Vec<u32>,
+}
+
+impl Card {
+    pub fn count_winners(&self) -> Result<u32, SolveError> {
+        self.numbers
+            .iter()
+            .filter(|number| self.winners.contains(number))
+            .count()
+            .try_into()
+            .map_err(|_| SolveError::Overflow)
+    }
+}
@@ -0,0 +1,68 @@
+use aoc_parse::{finish, labelled, lines, number, numbers, IResult};
+use nom::character::complete::space0;
+use nom::combinator::cut;
+use nom::sequence::preceded;
+
+use crate::model::Card;
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Vec<Card>, SolveError> {
+    Ok(finish(input, lines(parse_card))?)
+}
+
+fn parse_card(input: &str) -> IResult<'_, Card> {
+    let (input, id) = labelled("Card", number)(input)?;
+    let (input, winners) = labelled(":", numbers)(input)?;
+    // The winning numbers only end at the `|`, so a card without one fails
+    // there instead of backtracking to the start of the line.
+    let (input, numbers) = cut(preceded(space0, labelled("|", numbers)))(input)?;
+    let card = Card {
+        id,
+        winners,
+        numbers,
+    };
+
+    Ok((input, card))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53", Card{id: 1, winners: vec![41, 48, 83, 86, 17], numbers: vec![83, 86, 6, 31, 17, 9, 48, 53]})]
+    #[case("Card  1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53", Card{id: 1, winners: vec![41, 48, 83, 86, 17], numbers: vec![83, 86, 6, 31, 17, 9, 48, 53]})]
+    #[case("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1", Card{id: 3, winners: vec![1, 21, 53, 59, 44], numbers: vec![69, 82, 63, 72, 16, 21, 14, 1]})]
+    #[case("Card 23: 41 48 83 86 17 | 83 86  6 31 17  9 48 53", Card{id: 23, winners: vec![41, 48, 83, 86, 17], numbers: vec![83, 86, 6, 31, 17, 9, 48, 53]})]
+    fn parse_card_with_examples(#[case] input: &str, #[case] expected: Card) {
+        let (input, result) = parse_card(input).unwrap();
+        assert_eq!(input, "");
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(
+        "Card 1: 41 48 | 83 86\nCard 2: 13 32 61 30",
+        "line 2, column 20: expected |"
+    )]
+    #[case(
+        "Card 1: 41 48 | 83 86\nCard 2: 13 32 x 30",
+        "line 2, column 15: expected | near \"x 30\""
+    )]
+    fn parse_input_with_missing_separator(#[case] input: &str, #[case] expected: &str) {
+        let error = parse_input(input).unwrap_err().to_string();
+        assert_eq!(error, format!("invalid input at {}", expected));
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(format!("example_{}", name), parse_input(&input).unwrap());
+    }
+}
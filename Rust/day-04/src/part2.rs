@@ -1,47 +1,59 @@
 use std::collections::BTreeMap;
 
-use crate::part1::parse_input;
+use aoc_core::{trace, Answer};
 
-pub fn process(input: &str) -> String {
-    let (_, cards) = parse_input(input).expect("should parse input");
-    let mut instances: BTreeMap<u32, u32> = BTreeMap::new();
+use crate::model::Card;
+use crate::SolveError;
+
+pub fn process(cards: &[Card]) -> Result<Answer, SolveError> {
+    let mut instances: BTreeMap<u64, u64> = BTreeMap::new();
 
     for card in cards {
         // Add the (current) instance of the card to the record and get the
         // total amount of copies of the current card that we have.
-        let copies = *instances
-            .entry(card.id)
-            .and_modify(|val| *val += 1)
-            .or_insert(1);
+        let copies = instances.entry(u64::from(card.id)).or_insert(0);
+        *copies = copies.checked_add(1).ok_or(SolveError::Overflow)?;
+        let copies = *copies;
+        let winners = card.count_winners()?;
+        trace!(
+            "card {}: {} copies, each winning a copy of the next {} cards",
+            card.id,
+            copies,
+            winners
+        );
 
         // For each winning number, we add the number of copies of the current
         // card of the new card to the record.
-        for id in 0..card.count_winners() {
-            let id = card.id + 1 + id;
-            instances
-                .entry(id)
-                .and_modify(|val| *val += copies)
-                .or_insert(copies);
+        for id in 0..winners {
+            let id = u64::from(card.id) + 1 + u64::from(id);
+            let instance = instances.entry(id).or_insert(0);
+            *instance = instance.checked_add(copies).ok_or(SolveError::Overflow)?;
         }
     }
 
-    instances.values().sum::<u32>().to_string()
+    let result = instances
+        .values()
+        .try_fold(0_u64, |total, copies| total.checked_add(*copies))
+        .ok_or(SolveError::Overflow)?;
+
+    Ok(result.into())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc_core::trace;
 
-    const INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+    use super::*;
+    use crate::parser::parse_input;
 
     #[test]
-    fn process_with_example_input() {
-        let result = process(INPUT);
-        assert_eq!(result, "30");
+    fn process_explains_copies_of_each_card() {
+        let cards = parse_input(include_str!("../examples/1.txt")).unwrap();
+        let (_, lines) = trace::capture(|| process(&cards));
+        assert_eq!(lines.len(), 6);
+        assert_eq!(
+            lines[3],
+            "card 4: 8 copies, each winning a copy of the next 1 cards"
+        );
     }
 }
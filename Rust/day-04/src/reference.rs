@@ -0,0 +1,75 @@
+//! Counts matches by comparing strings and plays out part 2 one copy at a
+//! time.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let mut total = 0;
+        for matches in matches(input) {
+            if matches > 0 {
+                total += 1 << (matches - 1);
+            }
+        }
+        Ok(Answer::from(total as u64))
+    }
+
+    /// Plays out the scratchcards one copy at a time.
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let matches = matches(input);
+        let mut pile: Vec<usize> = (0..matches.len()).collect();
+
+        let mut scratched = 0u64;
+        while let Some(card) = pile.pop() {
+            scratched += 1;
+            for copy in card + 1..=card + matches[card] {
+                if copy < matches.len() {
+                    pile.push(copy);
+                }
+            }
+        }
+        Ok(scratched.into())
+    }
+}
+
+/// How many of each card's numbers are winning numbers.
+fn matches(input: &str) -> Vec<usize> {
+    input
+        .lines()
+        .map(|line| {
+            let (_, numbers) = line.split_once(": ").unwrap();
+            let (winners, numbers) = numbers.split_once(" | ").unwrap();
+            let winners: Vec<&str> = winners.split_whitespace().collect();
+            numbers
+                .split_whitespace()
+                .filter(|number| winners.contains(number))
+                .count()
+        })
+        .collect()
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..100usize,
+    cases: 256,
+);
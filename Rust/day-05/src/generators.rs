@@ -0,0 +1,83 @@
+use rand::seq::{index, SliceRandom};
+use rand::{Rng, RngCore};
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// An almanac with `size` seed ranges and `size` ranges in every map, over
+/// values below `size` million.
+///
+/// The seed ranges cover about a quarter of the values, so the work of
+/// checking every seed in part 2 grows with the square of `size`.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.max(1);
+    let space = 1_000_000 * size as u64;
+
+    let seeds: Vec<String> = (0..size)
+        .flat_map(|_| {
+            let start = rng.gen_range(0..space);
+            let length = rng.gen_range(1..=space / (4 * size as u64));
+            [start, length]
+        })
+        .map(|value| value.to_string())
+        .collect();
+
+    let maps: Vec<String> = MAPS
+        .iter()
+        .map(|name| format!("{} map:\n{}", name, map(rng, size, space)))
+        .collect();
+
+    format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n\n"))
+}
+
+/// `size` mappings whose source ranges don't overlap.
+fn map(rng: &mut dyn RngCore, size: usize, space: u64) -> String {
+    let mut bounds: Vec<u64> = index::sample(rng, space as usize, 2 * size)
+        .into_iter()
+        .map(|bound| bound as u64)
+        .collect();
+    bounds.sort_unstable();
+
+    let mut mappings: Vec<String> = bounds
+        .chunks_exact(2)
+        .map(|bounds| {
+            let (source, length) = (bounds[0], bounds[1] - bounds[0]);
+            let dest = rng.gen_range(0..space);
+            format!("{} {} {}", dest, source, length)
+        })
+        .collect();
+    mappings.shuffle(rng);
+
+    mappings.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn maps_do_not_overlap() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut ranges: Vec<(u64, u64)> = map(&mut rng, 20, 1_000)
+            .lines()
+            .map(|line| {
+                let values: Vec<u64> = line.split(' ').map(|v| v.parse().unwrap()).collect();
+                (values[1], values[1] + values[2])
+            })
+            .collect();
+        ranges.sort_unstable();
+
+        assert_eq!(ranges.len(), 20);
+        assert!(ranges.windows(2).all(|pair| pair[0].1 <= pair[1].0));
+    }
+}
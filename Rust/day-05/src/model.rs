@@ -0,0 +1,107 @@
+use std::ops::Range;
+
+#[derive(Debug)]
+pub struct Mapping(pub Range<u64>, pub Range<u64>);
+
+#[derive(Debug)]
+pub struct Mapper {
+    pub ranges: Vec<Mapping>,
+}
+
+impl Mapper {
+    pub fn look_up(&self, value: u64) -> u64 {
+        for mapping in self.ranges.iter() {
+            if mapping.0.contains(&value) {
+                let offset = value - mapping.0.start;
+                return mapping.1.start + offset;
+            }
+        }
+        value
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub seed_to_soil: Mapper,
+    pub soil_to_fertilizer: Mapper,
+    pub fertilizer_to_water: Mapper,
+    pub water_to_light: Mapper,
+    pub light_to_temperature: Mapper,
+    pub temperature_to_humidity: Mapper,
+    pub humidity_to_location: Mapper,
+}
+
+impl Almanac {
+    /// Names of the values in [`Almanac::chain`], in order.
+    pub const CATEGORIES: [&'static str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    pub fn find_location(&self, seed: u64) -> u64 {
+        self.chain(seed)[7]
+    }
+
+    /// The seed followed by the value it maps to in each category.
+    pub fn chain(&self, seed: u64) -> [u64; 8] {
+        let soil = self.seed_to_soil.look_up(seed);
+        let fertilizer = self.soil_to_fertilizer.look_up(soil);
+        let water = self.fertilizer_to_water.look_up(fertilizer);
+        let light = self.water_to_light.look_up(water);
+        let temperature = self.light_to_temperature.look_up(light);
+        let humidity = self.temperature_to_humidity.look_up(temperature);
+        let location = self.humidity_to_location.look_up(humidity);
+
+        [
+            seed,
+            soil,
+            fertilizer,
+            water,
+            light,
+            temperature,
+            humidity,
+            location,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parser::parse_input;
+
+    const INPUT: &str = include_str!("../examples/1.txt");
+
+    #[rstest]
+    #[case(79, 81)]
+    #[case(14, 14)]
+    #[case(55, 57)]
+    #[case(13, 13)]
+    fn mapper_look_up_with_example(#[case] seed: u64, #[case] expected: u64) {
+        let mapper = Mapper {
+            ranges: vec![Mapping(98..100, 50..52), Mapping(50..98, 52..100)],
+        };
+        let result = mapper.look_up(seed);
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(79, 82)]
+    #[case(14, 43)]
+    #[case(55, 86)]
+    #[case(13, 35)]
+    fn find_location_with_example_input(#[case] seed: u64, #[case] expected: u64) {
+        let almanac = parse_input(INPUT).unwrap();
+        let result = almanac.find_location(seed);
+        assert_eq!(result, expected);
+    }
+}
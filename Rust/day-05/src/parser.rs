@@ -0,0 +1,101 @@
+use aoc_parse::{blank_line, finish, labelled, lines, number, numbers, section, Error, IResult};
+use nom::{
+    character::complete::space1,
+    error::{context, ErrorKind},
+    sequence::{preceded, tuple},
+};
+
+use crate::model::{Almanac, Mapper, Mapping};
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Almanac, SolveError> {
+    Ok(finish(input, parse_almanac)?)
+}
+
+fn parse_almanac<'a>(input: &'a str) -> IResult<'a, Almanac> {
+    let (mut input, seeds) = labelled("seeds:", numbers)(input)?;
+
+    // Each map is a section of its own, following the previous one.
+    let mut map = |header| -> Result<Mapper, nom::Err<Error<'a>>> {
+        let (rest, mapper) = preceded(blank_line, section(header, parse_mapper))(input)?;
+        input = rest;
+        Ok(mapper)
+    };
+
+    let almanac = Almanac {
+        seeds,
+        seed_to_soil: map("seed-to-soil map")?,
+        soil_to_fertilizer: map("soil-to-fertilizer map")?,
+        fertilizer_to_water: map("fertilizer-to-water map")?,
+        water_to_light: map("water-to-light map")?,
+        light_to_temperature: map("light-to-temperature map")?,
+        temperature_to_humidity: map("temperature-to-humidity map")?,
+        humidity_to_location: map("humidity-to-location map")?,
+    };
+
+    Ok((input, almanac))
+}
+
+fn parse_mapper(input: &str) -> IResult<'_, Mapper> {
+    let (input, ranges) = lines(parse_mapping)(input)?;
+
+    Ok((input, Mapper { ranges }))
+}
+
+fn parse_mapping(line: &str) -> IResult<'_, Mapping> {
+    let (input, (dest, _, source, _, length)) = context(
+        "mapping",
+        tuple((number::<u64>, space1, number::<u64>, space1, number::<u64>)),
+    )(line)?;
+
+    // Ranges running past `u64::MAX` can't be looked up.
+    let (Some(source_end), Some(dest_end)) = (source.checked_add(length), dest.checked_add(length))
+    else {
+        return Err(nom::Err::Failure(Error {
+            input: line,
+            code: ErrorKind::Verify,
+            context: Some("ranges within 64 bits"),
+        }));
+    };
+
+    Ok((input, Mapping(source..source_end, dest..dest_end)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn parse_input_with_missing_section() {
+        let input = INPUT.replace("water-to-light", "water-to-lamp");
+        let error = parse_input(&input).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "invalid input at line 18, column 1: expected water-to-light map near \"water-to-lamp map:\""
+        );
+    }
+
+    #[test]
+    fn parse_input_with_overflowing_range() {
+        let input = INPUT.replace("50 98 2", "50 18446744073709551615 2");
+        let error = parse_input(&input).unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "invalid input at line 4, column 1: expected ranges within 64 bits near \"50 18446744073709551\""
+        );
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(format!("example_{}", name), parse_input(&input).unwrap());
+    }
+}
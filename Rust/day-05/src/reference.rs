@@ -0,0 +1,87 @@
+//! Sends seeds through the maps one at a time, every seed of the ranges in
+//! part 2.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let (seeds, maps) = almanac(input);
+        let lowest = seeds.iter().map(|&seed| location(seed, &maps)).min();
+        Ok(lowest.unwrap().into())
+    }
+
+    /// Checks every single seed in every range.
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let (seeds, maps) = almanac(input);
+        let lowest = seeds
+            .chunks(2)
+            .flat_map(|range| range[0]..range[0] + range[1])
+            .map(|seed| location(seed, &maps))
+            .min();
+        Ok(lowest.unwrap().into())
+    }
+}
+
+/// The seeds, and each map's `[destination, source, length]` lines.
+fn almanac(input: &str) -> (Vec<u64>, Vec<Vec<[u64; 3]>>) {
+    let mut blocks = input.split("\n\n");
+    let seeds = blocks
+        .next()
+        .unwrap()
+        .trim_start_matches("seeds:")
+        .split_whitespace()
+        .map(|seed| seed.parse().unwrap())
+        .collect();
+    let maps = blocks
+        .map(|block| {
+            block
+                .lines()
+                .skip(1)
+                .map(|line| {
+                    let mut values = line.split_whitespace().map(|v| v.parse().unwrap());
+                    [(); 3].map(|_| values.next().unwrap())
+                })
+                .collect()
+        })
+        .collect();
+    (seeds, maps)
+}
+
+fn location(seed: u64, maps: &[Vec<[u64; 3]>]) -> u64 {
+    let mut value = seed;
+    for map in maps {
+        for &[destination, source, length] in map {
+            if source <= value && value < source + length {
+                value = value - source + destination;
+                break;
+            }
+        }
+    }
+    value
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..2usize,
+    cases: 16,
+);
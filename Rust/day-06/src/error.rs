@@ -0,0 +1,14 @@
+use aoc_parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveError {
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    #[error("found {times} race times but {distances} record distances")]
+    MismatchedRaces { times: usize, distances: usize },
+    #[error("kerned race numbers do not fit in 64 bits")]
+    KernedTooLarge,
+    #[error("answer does not fit in a u64")]
+    Overflow,
+}
@@ -0,0 +1,98 @@
+use rand::seq::index;
+use rand::{Rng, RngCore};
+
+/// Largest race time generated, so that the record distances of the kerned
+/// race still fit in a `u64`.
+pub const MAX_TIME: usize = 4_000_000_000;
+
+/// Up to four races that, read as a single race with the kerning ignored,
+/// last between `size / 2` and `size` milliseconds (at most [`MAX_TIME`]).
+///
+/// The kerned race always has ways to win, but the individual races may not.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.clamp(8, MAX_TIME) as u64;
+    let time = rng.gen_range(size / 2..=size);
+    let distance = rng.gen_range(time * time / 8..time * time / 4);
+
+    let (time, distance) = (time.to_string(), distance.to_string());
+    let races = rng
+        .gen_range(1..=4)
+        .min(cuts(&time).len() + 1)
+        .min(cuts(&distance).len() + 1);
+    let times = split(rng, &time, races);
+    let distances = split(rng, &distance, races);
+
+    let widths: Vec<usize> = times
+        .iter()
+        .zip(&distances)
+        .map(|(time, distance)| time.len().max(distance.len()))
+        .collect();
+    let row = |values: &[&str]| -> String {
+        values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("  {:>width$}", value))
+            .collect()
+    };
+
+    format!("Time:    {}\nDistance:{}", row(&times), row(&distances))
+}
+
+/// Positions `digits` can be split at without a piece starting with a zero.
+fn cuts(digits: &str) -> Vec<usize> {
+    (1..digits.len())
+        .filter(|&at| !digits[at..].starts_with('0'))
+        .collect()
+}
+
+fn split<'a>(rng: &mut dyn RngCore, digits: &'a str, pieces: usize) -> Vec<&'a str> {
+    let cuts = cuts(digits);
+    let mut at: Vec<usize> = index::sample(rng, cuts.len(), pieces - 1)
+        .into_iter()
+        .map(|index| cuts[index])
+        .collect();
+    at.sort_unstable();
+
+    let mut start = 0;
+    let mut pieces = Vec::new();
+    for end in at.into_iter().chain([digits.len()]) {
+        pieces.push(&digits[start..end]);
+        start = end;
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{solve, Part};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::model::kerned;
+    use crate::parser::parse_input;
+    use crate::Solution;
+
+    #[rstest]
+    fn kerned_race_has_ways_to_win(
+        #[values(1, 2, 3)] seed: u64,
+        #[values(10, 100_000)] size: usize,
+    ) {
+        let input = generate(&mut StdRng::seed_from_u64(seed), size);
+        assert!(solve::<Solution>(&input, Part::Two).unwrap().as_integer() > Some(0));
+    }
+
+    #[test]
+    fn kerned_race_fits() {
+        let input = generate(&mut StdRng::seed_from_u64(1), usize::MAX);
+        let race = kerned(&parse_input(&input).unwrap()).unwrap();
+        assert!(race.time <= MAX_TIME as u64);
+    }
+
+    #[test]
+    fn split_skips_leading_zeros() {
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(split(&mut rng, "1002", 2), vec!["100", "2"]);
+    }
+}
@@ -0,0 +1,92 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    /// Number of button hold times that beat the record distance.
+    ///
+    /// The distance travelled peaks when holding for half the race and falls
+    /// off the same way on both sides, so the winning hold times are the ones
+    /// between the shortest winning hold and its mirror.
+    pub fn ways_to_win(&self) -> u64 {
+        let wins =
+            |hold: u64| u128::from(self.distance) < u128::from(self.time - hold) * u128::from(hold);
+
+        let (mut shortest, mut longest) = (0, self.time / 2);
+        if !wins(longest) {
+            return 0;
+        }
+        while shortest < longest {
+            let middle = shortest + (longest - shortest) / 2;
+            if wins(middle) {
+                longest = middle;
+            } else {
+                shortest = middle + 1;
+            }
+        }
+
+        self.time - 2 * shortest + 1
+    }
+}
+
+/// Reads the races as a single one, ignoring the kerning between the numbers.
+pub fn kerned(races: &[Race]) -> Option<Race> {
+    let time = concat(races.iter().map(|race| race.time))?;
+    let distance = concat(races.iter().map(|race| race.distance))?;
+
+    Some(Race { time, distance })
+}
+
+fn concat(values: impl Iterator<Item = u64>) -> Option<u64> {
+    values
+        .map(|value| value.to_string())
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(7, 9, 4)]
+    #[case(15, 40, 8)]
+    #[case(30, 200, 9)]
+    #[case(71530, 940200, 71503)]
+    #[case(10, 100, 0)]
+    #[case(0, 0, 0)]
+    #[case(u64::MAX, 0, u64::MAX - 1)]
+    fn ways_to_win(#[case] time: u64, #[case] distance: u64, #[case] expected: u64) {
+        assert_eq!(Race { time, distance }.ways_to_win(), expected);
+    }
+
+    #[test]
+    fn kerned_races() {
+        let races = [
+            Race {
+                time: 7,
+                distance: 9,
+            },
+            Race {
+                time: 15,
+                distance: 40,
+            },
+            Race {
+                time: 30,
+                distance: 200,
+            },
+        ];
+        assert_eq!(
+            kerned(&races),
+            Some(Race {
+                time: 71530,
+                distance: 940200
+            })
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use aoc_parse::{finish, labelled, numbers, IResult};
+use nom::{
+    character::complete::line_ending,
+    sequence::{preceded, tuple},
+};
+
+use crate::model::Race;
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Vec<Race>, SolveError> {
+    let (times, distances) = finish(input, parse_columns)?;
+    if times.len() != distances.len() {
+        return Err(SolveError::MismatchedRaces {
+            times: times.len(),
+            distances: distances.len(),
+        });
+    }
+
+    let races = times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
+
+    Ok(races)
+}
+
+fn parse_columns(input: &str) -> IResult<'_, (Vec<u64>, Vec<u64>)> {
+    let (input, (times, distances)) = tuple((
+        labelled("Time:", numbers),
+        preceded(line_ending, labelled("Distance:", numbers)),
+    ))(input)?;
+
+    Ok((input, (times, distances)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn parse_input_with_example() {
+        let races = parse_input(INPUT).unwrap();
+        assert_eq!(
+            races,
+            vec![
+                Race {
+                    time: 7,
+                    distance: 9
+                },
+                Race {
+                    time: 15,
+                    distance: 40
+                },
+                Race {
+                    time: 30,
+                    distance: 200
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_input_with_number_too_large() {
+        let result = parse_input("Time:  7  15  30\nDistance:  9  40  99999999999999999999");
+        let error = result.unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "invalid input at line 2, column 19: unexpected input near \"99999999999999999999\""
+        );
+    }
+
+    #[test]
+    fn parse_input_with_missing_distance() {
+        let result = parse_input("Time:  7  15  30\nDistance:  9  40");
+        assert_eq!(
+            result,
+            Err(SolveError::MismatchedRaces {
+                times: 3,
+                distances: 2
+            })
+        );
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(format!("example_{}", name), parse_input(&input).unwrap());
+    }
+}
@@ -0,0 +1,64 @@
+//! Tries every hold time of every race.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let mut lines = input.lines().map(|line| {
+            let (_, values) = line.split_once(':').unwrap();
+            values
+                .split_whitespace()
+                .map(|value| value.parse().unwrap())
+                .collect::<Vec<u64>>()
+        });
+        let (times, distances) = (lines.next().unwrap(), lines.next().unwrap());
+
+        let mut product = 1;
+        for (time, distance) in times.into_iter().zip(distances) {
+            product *= ways_to_win(time, distance);
+        }
+        Ok(product.into())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let mut lines = input.lines().map(|line| {
+            let (_, value) = line.split_once(':').unwrap();
+            value.replace(' ', "").parse::<u64>().unwrap()
+        });
+        let (time, distance) = (lines.next().unwrap(), lines.next().unwrap());
+        Ok(ways_to_win(time, distance).into())
+    }
+}
+
+/// Tries holding the button for every possible number of milliseconds.
+fn ways_to_win(time: u64, distance: u64) -> u64 {
+    (0..=time)
+        .filter(|hold| hold * (time - hold) > distance)
+        .count() as u64
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 8..100_000usize,
+    cases: 64,
+);
@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Most different hands there are.
+pub const MAX_HANDS: usize = 13usize.pow(5);
+
+/// A list of `size` different hands (at most [`MAX_HANDS`]) with bids of up
+/// to 1000. Hands don't repeat so that their ranking is unambiguous.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.min(MAX_HANDS);
+    let mut seen = HashSet::new();
+    let mut lines = Vec::with_capacity(size);
+
+    while lines.len() < size {
+        let hand: Vec<u8> = (0..5).map(|_| *CARDS.choose(rng).unwrap()).collect();
+        if seen.insert(hand.clone()) {
+            let hand = String::from_utf8(hand).unwrap();
+            lines.push(format!("{} {}", hand, rng.gen_range(1..=1000)));
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn hands_are_unique() {
+        let input = generate(&mut StdRng::seed_from_u64(1), 100);
+        assert_eq!(input.lines().collect::<HashSet<_>>().len(), 100);
+    }
+}
@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// A camel card. Variants are declared from weakest to strongest, with the
+/// joker only appearing once jacks are read as jokers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum Card {
+    Joker,
+    Number(u32),
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl FromStr for Card {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(Self::Number(2)),
+            "3" => Ok(Self::Number(3)),
+            "4" => Ok(Self::Number(4)),
+            "5" => Ok(Self::Number(5)),
+            "6" => Ok(Self::Number(6)),
+            "7" => Ok(Self::Number(7)),
+            "8" => Ok(Self::Number(8)),
+            "9" => Ok(Self::Number(9)),
+            "T" => Ok(Self::Number(10)),
+            "J" => Ok(Self::Jack),
+            "Q" => Ok(Self::Queen),
+            "K" => Ok(Self::King),
+            "A" => Ok(Self::Ace),
+            _ => Err(String::from("Invalid card")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hand(pub Vec<Card>);
+
+impl Hand {
+    /// The same hand with every jack read as a joker.
+    pub fn with_jokers(&self) -> Hand {
+        let cards = self
+            .0
+            .iter()
+            .map(|card| match card {
+                Card::Jack => Card::Joker,
+                card => *card,
+            })
+            .collect();
+        Hand(cards)
+    }
+
+    pub fn get_type(&self) -> HandType {
+        let mut frequencies: Vec<_> = self
+            .0
+            .iter()
+            .filter(|c| **c != Card::Joker)
+            .fold(HashMap::new(), |mut map, card| {
+                map.entry(card).and_modify(|f| *f += 1).or_insert(1);
+                map
+            })
+            .into_values()
+            .collect();
+        frequencies.sort();
+
+        // Jokers always do best by joining the most frequent card.
+        let jokers = self.0.iter().filter(|c| **c == Card::Joker).count();
+        match frequencies.last_mut() {
+            Some(highest) => *highest += jokers,
+            None => frequencies.push(jokers),
+        }
+
+        match frequencies.as_slice() {
+            [5] => HandType::FiveOfAKind,
+            [1, 4] => HandType::FourOfAKind,
+            [2, 3] => HandType::FullHouse,
+            [1, 1, 3] => HandType::ThreeOfAKind,
+            [1, 2, 2] => HandType::TwoPair,
+            [1, 1, 1, 2] => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split("")
+            .filter(|card| !card.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<Card>, _>>()
+            .map(Hand)
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let result = self.get_type().partial_cmp(&other.get_type());
+        if let Some(Ordering::Equal) = result {
+            for (c1, c2) in self.0.iter().zip(other.0.iter()) {
+                match c1.partial_cmp(c2) {
+                    Some(Ordering::Equal) => (),
+                    Some(ordering) => return Some(ordering),
+                    _ => (),
+                }
+            }
+        }
+        result
+    }
+}
+
+pub type Bid = u32;
+
+/// Ranks the hands from weakest to strongest and sums each bid times its rank.
+pub fn total_winnings(mut hands: Vec<(Hand, Bid)>) -> u64 {
+    hands.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap());
+
+    hands
+        .iter()
+        .zip(1..)
+        .map(|((_, bid), i)| u64::from(*bid) * i)
+        .sum::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn hand(cards: &str) -> Hand {
+        cards.parse().unwrap()
+    }
+
+    #[rstest]
+    #[case("AAAAA", "AA8AA", Some(Ordering::Greater))]
+    #[case("AA8AA", "AA8AA", Some(Ordering::Equal))]
+    #[case("AA8AA", "AA9AA", Some(Ordering::Less))]
+    #[case("33332", "2AAAA", Some(Ordering::Greater))]
+    #[case("77888", "77788", Some(Ordering::Greater))]
+    fn hand_partial_ord(#[case] a: &str, #[case] b: &str, #[case] expected: Option<Ordering>) {
+        let result = hand(a).partial_cmp(&hand(b));
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("T55J5", "QQQJA", Some(Ordering::Less))]
+    #[case("QQQJA", "KTJJT", Some(Ordering::Less))]
+    #[case("AAJAA", "AJAAA", Some(Ordering::Greater))]
+    #[case("22222", "J2222", Some(Ordering::Greater))]
+    fn hand_with_jokers_partial_ord(
+        #[case] a: &str,
+        #[case] b: &str,
+        #[case] expected: Option<Ordering>,
+    ) {
+        let result = hand(a).with_jokers().partial_cmp(&hand(b).with_jokers());
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("AAAAA", HandType::FiveOfAKind)]
+    #[case("AA8AA", HandType::FourOfAKind)]
+    #[case("23332", HandType::FullHouse)]
+    #[case("TTT98", HandType::ThreeOfAKind)]
+    #[case("23432", HandType::TwoPair)]
+    #[case("A23A4", HandType::OnePair)]
+    #[case("23456", HandType::HighCard)]
+    #[case("KTJJT", HandType::TwoPair)]
+    fn hand_get_type(#[case] cards: &str, #[case] expected: HandType) {
+        let result = hand(cards).get_type();
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case("JJJJJ", HandType::FiveOfAKind)]
+    #[case("JJAJJ", HandType::FiveOfAKind)]
+    #[case("KJAJJ", HandType::FourOfAKind)]
+    #[case("KKAJJ", HandType::FourOfAKind)]
+    #[case("KKAAJ", HandType::FullHouse)]
+    #[case("KKAQJ", HandType::ThreeOfAKind)]
+    #[case("K2AQJ", HandType::OnePair)]
+    #[case("32T3K", HandType::OnePair)]
+    #[case("KK677", HandType::TwoPair)]
+    #[case("T55J5", HandType::FourOfAKind)]
+    #[case("KTJJT", HandType::FourOfAKind)]
+    #[case("QQQJA", HandType::FourOfAKind)]
+    #[case("QJJQ2", HandType::FourOfAKind)]
+    fn hand_with_jokers_get_type(#[case] cards: &str, #[case] expected: HandType) {
+        let result = hand(cards).with_jokers().get_type();
+        assert_eq!(result, expected);
+    }
+}
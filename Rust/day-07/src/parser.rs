@@ -0,0 +1,150 @@
+use aoc_parse::{finish, lines, number, IResult};
+use nom::bytes::complete::take;
+use nom::character::complete::{satisfy, space1};
+use nom::combinator::{cut, map, map_res, peek};
+use nom::error::context;
+use nom::multi::count;
+use nom::sequence::{preceded, separated_pair};
+
+use crate::model::{Bid, Card, Hand};
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Vec<(Hand, Bid)>, SolveError> {
+    Ok(finish(input, lines(parse_line))?)
+}
+
+/// A hand and its bid. Anything but whitespace at the start of a line has to
+/// be one, so a bad card is reported where it is rather than at the start of
+/// the line.
+fn parse_line(input: &str) -> IResult<'_, (Hand, Bid)> {
+    preceded(
+        peek(satisfy(|c| !c.is_whitespace())),
+        cut(separated_pair(
+            parse_hand,
+            context("a space after five cards", space1),
+            number,
+        )),
+    )(input)
+}
+
+fn parse_hand(input: &str) -> IResult<'_, Hand> {
+    map(count(parse_card, 5), Hand)(input)
+}
+
+fn parse_card(input: &str) -> IResult<'_, Card> {
+    context(
+        "a card (2-9, T, J, Q, K or A)",
+        map_res(take(1_usize), str::parse),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../examples/1.txt");
+
+    #[rstest]
+    #[case(
+        "32T3K 765\nT55X5 684",
+        "line 2, column 4: expected a card (2-9, T, J, Q, K or A) near \"X5 684\""
+    )]
+    #[case(
+        "32T3K 765\nx55T5 684",
+        "line 2, column 1: expected a card (2-9, T, J, Q, K or A) near \"x55T5 684\""
+    )]
+    fn parse_input_with_invalid_card(#[case] input: &str, #[case] expected: &str) {
+        let error = parse_input(input).unwrap_err().to_string();
+        assert_eq!(error, format!("invalid input at {}", expected));
+    }
+
+    #[test]
+    fn parse_input_with_six_cards() {
+        let result = parse_input("32T3K 765\nT55J55 684");
+        let error = result.unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "invalid input at line 2, column 6: expected a space after five cards near \"5 684\""
+        );
+    }
+
+    #[test]
+    fn parse_input_with_example() {
+        let hands = parse_input(INPUT).unwrap();
+        assert_eq!(
+            hands,
+            vec![
+                (
+                    Hand(vec![
+                        Card::Number(3),
+                        Card::Number(2),
+                        Card::Number(10),
+                        Card::Number(3),
+                        Card::King
+                    ]),
+                    765
+                ),
+                (
+                    Hand(vec![
+                        Card::Number(10),
+                        Card::Number(5),
+                        Card::Number(5),
+                        Card::Jack,
+                        Card::Number(5)
+                    ]),
+                    684
+                ),
+                (
+                    Hand(vec![
+                        Card::King,
+                        Card::King,
+                        Card::Number(6),
+                        Card::Number(7),
+                        Card::Number(7)
+                    ]),
+                    28
+                ),
+                (
+                    Hand(vec![
+                        Card::King,
+                        Card::Number(10),
+                        Card::Jack,
+                        Card::Jack,
+                        Card::Number(10)
+                    ]),
+                    220,
+                ),
+                (
+                    Hand(vec![
+                        Card::Queen,
+                        Card::Queen,
+                        Card::Queen,
+                        Card::Jack,
+                        Card::Ace
+                    ]),
+                    483,
+                ),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        let hands: Vec<_> = parse_input(&input)
+            .unwrap()
+            .into_iter()
+            .map(|(hand, bid)| {
+                let types = (hand.get_type(), hand.with_jokers().get_type());
+                (hand, bid, types)
+            })
+            .collect();
+        insta::assert_debug_snapshot!(format!("example_{}", name), hands);
+    }
+}
@@ -0,0 +1,99 @@
+//! Ranks hands by counting cards and, for part 2, by trying every card in
+//! place of each joker.
+
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+const ORDER: &str = "23456789TJQKA";
+const JOKER_ORDER: &str = "J23456789TQKA";
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(winnings(input, ORDER, kind))
+    }
+
+    /// Tries every card in place of each joker, one joker at a time.
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Ok(winnings(input, JOKER_ORDER, best_kind))
+    }
+}
+
+fn winnings(input: &str, order: &str, kind: fn(&str) -> u8) -> Answer {
+    let mut hands: Vec<(&str, u64)> = input
+        .lines()
+        .map(|line| {
+            let (hand, bid) = line.split_once(' ').unwrap();
+            (hand, bid.parse().unwrap())
+        })
+        .collect();
+
+    let strength =
+        |hand: &str| -> Vec<usize> { hand.chars().map(|card| order.find(card).unwrap()).collect() };
+    hands.sort_by_key(|(hand, _)| (kind(hand), strength(hand)));
+
+    let mut total = 0;
+    for (rank, (_, bid)) in (1..).zip(hands) {
+        total += rank * bid;
+    }
+    total.into()
+}
+
+/// From 0 for high card up to 6 for five of a kind.
+fn kind(hand: &str) -> u8 {
+    let mut counts: Vec<usize> = ORDER
+        .chars()
+        .map(|card| hand.chars().filter(|c| *c == card).count())
+        .filter(|count| *count > 0)
+        .collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    match counts[..] {
+        [5] => 6,
+        [4, 1] => 5,
+        [3, 2] => 4,
+        [3, 1, 1] => 3,
+        [2, 2, 1] => 2,
+        [2, 1, 1, 1] => 1,
+        _ => 0,
+    }
+}
+
+fn best_kind(hand: &str) -> u8 {
+    match hand.find('J') {
+        Some(joker) => JOKER_ORDER[1..]
+            .chars()
+            .map(|card| {
+                let mut substituted = hand.to_string();
+                substituted.replace_range(joker..=joker, &card.to_string());
+                best_kind(&substituted)
+            })
+            .max()
+            .unwrap(),
+        None => kind(hand),
+    }
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..50usize,
+    cases: 64,
+);
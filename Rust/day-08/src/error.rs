@@ -0,0 +1,18 @@
+use aoc_parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveError {
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    #[error("no instructions to follow")]
+    NoInstructions,
+    #[error("node {0:?} is not in the network")]
+    UnknownNode(String),
+    #[error("walking from {0:?} never reaches an end node")]
+    NoEnd(String),
+    #[error("no starting nodes ending in 'A' in the network")]
+    NoStartNodes,
+    #[error("answer does not fit in a u64")]
+    Overflow,
+}
@@ -0,0 +1,116 @@
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore};
+
+const GHOSTS: usize = 6;
+const LAPS: [usize; 10] = [5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// A network with `size` instructions and six ghosts.
+///
+/// Like the real inputs, every ghost walks a loop from its `..A` node to its
+/// `..Z` node and, since the `..Z` node leads to the same node as the `..A`
+/// one, around the same loop again. Each loop takes a different prime number
+/// of passes through the instructions, so the ghosts first meet on `..Z`
+/// nodes after the least common multiple of their loops. The first ghost
+/// walks from `AAA` to `ZZZ`.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let size = size.max(1);
+    let instructions: Vec<char> = (0..size)
+        .map(|_| if rng.gen() { 'L' } else { 'R' })
+        .collect();
+
+    let mut names = (0..).map(name);
+    let mut nodes = Vec::new();
+    for (ghost, laps) in LAPS.choose_multiple(rng, GHOSTS).enumerate() {
+        let (start, end) = match ghost {
+            0 => (String::from("AAA"), String::from("ZZZ")),
+            _ => {
+                let prefix = letters(ghost, 2);
+                (format!("{}A", prefix), format!("{}Z", prefix))
+            }
+        };
+
+        let length = laps * size;
+        let mut path = vec![start];
+        path.extend(names.by_ref().take(length - 1));
+        path.push(end);
+
+        for (step, pair) in path.windows(2).enumerate() {
+            nodes.push((pair[0].clone(), instructions[step % size], pair[1].clone()));
+        }
+        // Leave the end node the same way as the start node.
+        nodes.push((path[length].clone(), instructions[0], path[1].clone()));
+    }
+
+    // The direction not taken leads anywhere, it is never followed.
+    let all: Vec<String> = nodes.iter().map(|(node, _, _)| node.clone()).collect();
+    let mut lines: Vec<String> = nodes
+        .into_iter()
+        .map(|(node, direction, next)| {
+            let other = all.choose(rng).unwrap();
+            match direction {
+                'L' => format!("{} = ({}, {})", node, next, other),
+                _ => format!("{} = ({}, {})", node, other, next),
+            }
+        })
+        .collect();
+    lines.shuffle(rng);
+
+    format!(
+        "{}\n\n{}",
+        instructions.into_iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+/// Name of the `index`th node that neither starts nor ends a ghost's walk.
+fn name(index: usize) -> String {
+    let last = char::from(b'B' + (index % 24) as u8);
+    format!("{}{}", letters(index / 24, 2), last)
+}
+
+/// `value` in base 26 with the letters `A` to `Z`, padded to `width`.
+fn letters(mut value: usize, width: usize) -> String {
+    let mut letters = Vec::new();
+    while value > 0 || letters.len() < width {
+        letters.push(b'A' + (value % 26) as u8);
+        value /= 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_core::{solve, Part};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::Solution;
+
+    // The reference walks every ghost one step at a time, far too slowly for
+    // all six, so check the answers against the loops the generator builds.
+    #[rstest]
+    fn steps_are_whole_rounds_of_instructions(#[values(1, 2, 3)] seed: u64) {
+        let input = generate(&mut StdRng::seed_from_u64(seed), 20);
+        let part1 = solve::<Solution>(&input, Part::One).unwrap();
+        let part2 = solve::<Solution>(&input, Part::Two).unwrap();
+
+        let (part1, part2) = (part1.as_integer().unwrap(), part2.as_integer().unwrap());
+        assert_eq!(part1 % 20, 0);
+        assert_eq!(part2 % part1, 0);
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let names: HashSet<String> = (0..100_000).map(name).collect();
+        assert_eq!(names.len(), 100_000);
+        assert!(names
+            .iter()
+            .all(|name| !name.ends_with('A') && !name.ends_with('Z')));
+        assert_eq!(name(0), "AAB");
+    }
+}
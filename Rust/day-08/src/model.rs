@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::SolveError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Left => f.write_str("left"),
+            Instruction::Right => f.write_str("right"),
+        }
+    }
+}
+
+impl TryFrom<char> for Instruction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(format!("Unknown instruction {:?}", value)),
+        }
+    }
+}
+
+pub type Nodes<'a> = BTreeMap<&'a str, (&'a str, &'a str)>;
+
+#[derive(Debug, PartialEq)]
+pub struct Network<'a> {
+    pub instructions: Vec<Instruction>,
+    pub nodes: Nodes<'a>,
+}
+
+impl<'a> Network<'a> {
+    /// Number of steps needed to walk from `start` to the first node accepted by `is_end`.
+    pub fn steps(
+        &self,
+        start: &'a str,
+        is_end: impl Fn(&str) -> bool,
+    ) -> Result<usize, SolveError> {
+        self.walk(start, is_end, |_| {})
+    }
+
+    /// Like [`Network::steps`], handing every step taken to `visit`.
+    ///
+    /// There are only so many pairs of node and position in the instructions,
+    /// so a walk that takes more steps than that is going round in circles.
+    pub fn walk(
+        &self,
+        start: &'a str,
+        is_end: impl Fn(&str) -> bool,
+        mut visit: impl FnMut(Step<'a>),
+    ) -> Result<usize, SolveError> {
+        let mut index = 0;
+        let mut cursor = start;
+
+        let states = self.nodes.len().saturating_mul(self.instructions.len());
+
+        while !is_end(cursor) {
+            if index > states {
+                return Err(SolveError::NoEnd(start.to_string()));
+            }
+
+            let node = self
+                .nodes
+                .get(cursor)
+                .ok_or_else(|| SolveError::UnknownNode(cursor.to_string()))?;
+
+            let instruction = self.instructions[index % self.instructions.len()];
+            let next = match instruction {
+                Instruction::Left => node.0,
+                Instruction::Right => node.1,
+            };
+            visit(Step {
+                number: index + 1,
+                from: cursor,
+                instruction,
+                to: next,
+            });
+            cursor = next;
+
+            index += 1;
+        }
+
+        Ok(index)
+    }
+}
+
+/// A move from one node to the next, numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step<'a> {
+    pub number: usize,
+    pub from: &'a str,
+    pub instruction: Instruction,
+    pub to: &'a str,
+}
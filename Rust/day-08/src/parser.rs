@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use aoc_parse::{blank_line, finish, key_pair, lines, IResult};
+use nom::{
+    character::complete::{alphanumeric1, anychar, line_ending},
+    combinator::{map_res, peek},
+    multi::many_till,
+    sequence::preceded,
+};
+
+use crate::model::{Instruction, Network, Nodes};
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Network<'_>, SolveError> {
+    let network = finish(input, parse_network)?;
+    if network.instructions.is_empty() {
+        return Err(SolveError::NoInstructions);
+    }
+
+    Ok(network)
+}
+
+fn parse_network(input: &str) -> IResult<'_, Network<'_>> {
+    let (input, instructions) = parse_instructions(input)?;
+    let (input, nodes) = preceded(blank_line, parse_nodes)(input)?;
+
+    Ok((
+        input,
+        Network {
+            instructions,
+            nodes,
+        },
+    ))
+}
+
+fn parse_instructions(input: &str) -> IResult<'_, Vec<Instruction>> {
+    let (input, (instructions, _)) =
+        many_till(map_res(anychar, Instruction::try_from), peek(line_ending))(input)?;
+
+    Ok((input, instructions))
+}
+
+fn parse_nodes(input: &str) -> IResult<'_, Nodes<'_>> {
+    let mut nodes = BTreeMap::new();
+    let (input, values) = lines(parse_node)(input)?;
+
+    for (key, value) in values {
+        nodes.insert(key, value);
+    }
+
+    Ok((input, nodes))
+}
+
+fn parse_node(input: &str) -> IResult<'_, (&str, (&str, &str))> {
+    key_pair(alphanumeric1, alphanumeric1, alphanumeric1)(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn parse_input_with_example() {
+        let network = parse_input("RL\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)").unwrap();
+        assert_eq!(
+            network,
+            Network {
+                instructions: vec![Instruction::Right, Instruction::Left],
+                nodes: BTreeMap::from([("AAA", ("BBB", "CCC")), ("BBB", ("AAA", "AAA"))]),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_input_without_instructions() {
+        let result = parse_input("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)");
+        assert_eq!(result, Err(SolveError::NoInstructions));
+    }
+
+    #[test]
+    fn parse_input_with_unknown_instruction() {
+        let result = parse_input("LRX\n\nAAA = (ZZZ, ZZZ)");
+        let error = result.unwrap_err().to_string();
+        assert_eq!(
+            error,
+            "invalid input at line 1, column 3: invalid value near \"X\""
+        );
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(format!("example_{}", name), parse_input(&input).unwrap());
+    }
+}
@@ -1,74 +1,63 @@
-use crate::part1::{parse_input, Instruction};
+use aoc_core::{trace, Answer};
 
-pub fn process(input: &str) -> String {
-    let (_, (instructions, nodes)) = parse_input(input).unwrap();
+use crate::model::Network;
+use crate::SolveError;
 
-    nodes
+pub fn process(network: &Network<'_>) -> Result<Answer, SolveError> {
+    // Each ghost loops back to its end node at the same pace, so only the
+    // first lap of each is walked and traced.
+    let steps = network
+        .nodes
         .keys()
         .filter(|key| key.ends_with('A'))
-        .map(|mut cursor| {
-            let mut cursor_index = 0;
-
-            loop {
-                if cursor.ends_with('Z') {
-                    break;
-                }
-                let node = nodes.get(cursor).expect("node should exist");
-                let instruction = instructions
-                    .get(cursor_index % instructions.len())
-                    .expect("instruction should exist");
-
-                cursor = match instruction {
-                    Instruction::Left => &node.0,
-                    Instruction::Right => &node.1,
-                };
-
-                cursor_index += 1;
-            }
-
-            cursor_index
+        .map(|start| {
+            let steps = network.steps(start, |node| node.ends_with('Z'))?;
+            trace!(
+                "ghost from {} reaches an end node every {} steps",
+                start,
+                steps
+            );
+            Ok(steps as u64)
         })
-        .reduce(least_common_multiple)
-        .expect("should have a result")
-        .to_string()
-}
-
-fn least_common_multiple(a: usize, b: usize) -> usize {
-    (a * b) / greatest_common_divisor(a, b)
-}
+        .collect::<Result<Vec<u64>, SolveError>>()?;
 
-fn greatest_common_divisor(mut a: usize, mut b: usize) -> usize {
-    while a != b {
-        if a > b {
-            a -= b;
-        } else {
-            b -= a;
-        }
+    if steps.is_empty() {
+        return Err(SolveError::NoStartNodes);
     }
-    a
+
+    let result = aoc_math::lcm_all(steps.iter().copied()).ok_or(SolveError::Overflow)?;
+    trace!(
+        "all ghosts first meet on end nodes after lcm {:?} = {} steps",
+        steps,
+        result
+    );
+
+    Ok(result.into())
 }
 
 #[cfg(test)]
 mod tests {
-    use rstest::rstest;
-
     use super::*;
+    use crate::parser::parse_input;
+
+    #[test]
+    fn process_explains_each_ghost_once() {
+        let network = parse_input(include_str!("../examples/3.txt")).unwrap();
+        let (_, lines) = aoc_core::trace::capture(|| process(&network));
+        assert_eq!(
+            lines,
+            [
+                "ghost from 11A reaches an end node every 2 steps",
+                "ghost from 22A reaches an end node every 3 steps",
+                "all ghosts first meet on end nodes after lcm [2, 3] = 6 steps",
+            ]
+        );
+    }
 
-    const INPUT: &str = "LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-
-    #[rstest]
-    #[case(INPUT, "6")]
-    fn process_with_example(#[case] input: &str, #[case] expected: String) {
-        let result = process(input);
-        assert_eq!(result, expected);
+    #[test]
+    fn process_without_start_nodes() {
+        let network = parse_input("LR\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        let result = process(&network);
+        assert_eq!(result, Err(SolveError::NoStartNodes));
     }
 }
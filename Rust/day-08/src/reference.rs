@@ -0,0 +1,110 @@
+//! Walks the network one step at a time, moving every ghost at once in part 2.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use aoc_core::{Answer, Solver};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let (instructions, nodes) = network(input);
+
+        let mut node = "AAA";
+        let mut steps = 0u64;
+        for direction in instructions.chars().cycle() {
+            if node == "ZZZ" {
+                break;
+            }
+            node = step(&nodes, node, direction);
+            steps += 1;
+        }
+        Ok(steps.into())
+    }
+
+    /// Moves every ghost at once until they all stand on a `..Z` node.
+    fn part2(input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        let (instructions, nodes) = network(input);
+
+        let mut ghosts: Vec<&str> = nodes.keys().filter(|n| n.ends_with('A')).copied().collect();
+        let mut steps = 0u64;
+        for direction in instructions.chars().cycle() {
+            if ghosts.iter().all(|ghost| ghost.ends_with('Z')) {
+                break;
+            }
+            for ghost in &mut ghosts {
+                *ghost = step(&nodes, ghost, direction);
+            }
+            steps += 1;
+        }
+        Ok(steps.into())
+    }
+}
+
+type Nodes<'a> = HashMap<&'a str, (&'a str, &'a str)>;
+
+fn network(input: &str) -> (&str, Nodes<'_>) {
+    let (instructions, nodes) = input.split_once("\n\n").unwrap();
+    let nodes = nodes
+        .lines()
+        .map(|line| {
+            let (node, next) = line.split_once(" = ").unwrap();
+            let (left, right) = next
+                .trim_matches(|c| c == '(' || c == ')')
+                .split_once(", ")
+                .unwrap();
+            (node, (left, right))
+        })
+        .collect();
+    (instructions.trim(), nodes)
+}
+
+fn step<'a>(nodes: &Nodes<'a>, node: &str, direction: char) -> &'a str {
+    let (left, right) = nodes[node];
+    match direction {
+        'L' => left,
+        _ => right,
+    }
+}
+
+/// Keeps only two of the generated ghosts, so that walking them all step by
+/// step ends in a reasonable time.
+#[cfg(test)]
+fn two_ghosts(input: String) -> String {
+    let mut starts = 0;
+    input
+        .lines()
+        .filter(|line| match line.split_once(" = ") {
+            Some((node, _)) if node.ends_with('A') && node != "AAA" => {
+                starts += 1;
+                starts == 1
+            }
+            _ => true,
+        })
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..20usize,
+    cases: 64,
+    prepare: crate::reference::two_ghosts,
+);
@@ -0,0 +1,17 @@
+#!/usr/bin/env sh
+# Seeds the corpus of every fuzz target with its day's examples.
+#
+# Usage: fuzz/seed-corpus.sh
+set -eu
+
+fuzz=$(cd "$(dirname "$0")" && pwd)
+
+for target in "$fuzz"/fuzz_targets/*.rs; do
+    target=$(basename "$target" .rs)
+    day=${target##*_}
+
+    mkdir -p "$fuzz/corpus/$target"
+    for example in "$fuzz/../day-$day/examples/"*.txt; do
+        cp "$example" "$fuzz/corpus/$target/example-$(basename "$example" .txt)"
+    done
+done
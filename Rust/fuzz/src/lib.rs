@@ -0,0 +1,16 @@
+//! Shared bodies of the fuzz targets, which only have to not panic and not
+//! hang: errors are fine.
+
+use aoc_core::Solver;
+
+pub fn parse<S: Solver>(input: &str) {
+    let _ = S::parse(input);
+}
+
+/// Solves both parts of whatever parses.
+pub fn solve<S: Solver>(input: &str) {
+    if let Ok(parsed) = S::parse(input) {
+        let _ = S::part1(&parsed);
+        let _ = S::part2(&parsed);
+    }
+}
@@ -0,0 +1,25 @@
+#!/usr/bin/env sh
+# Removes every plaintext `input.txt` from the git history, keeping the
+# encrypted `input.txt.age` files. This rewrites every commit, so the remote
+# has to be force-pushed and existing clones re-cloned afterwards. Extra
+# arguments, such as `--force` outside of a fresh clone, go to filter-repo.
+#
+# Usage: scripts/purge-inputs.sh [filter-repo options]
+set -eu
+
+if ! git filter-repo --version >/dev/null 2>&1; then
+    echo "git filter-repo is required: https://github.com/newren/git-filter-repo" >&2
+    exit 1
+fi
+
+cd "$(git -C "$(dirname "$0")" rev-parse --show-toplevel)"
+
+# filter-repo forgets the remotes, so put origin back for the force push.
+remote=$(git remote get-url origin 2>/dev/null || true)
+
+git filter-repo --invert-paths --path-glob 'Rust/day-*/input.txt' "$@"
+
+if [ -n "$remote" ]; then
+    git remote add origin "$remote"
+    echo "History rewritten; push it with: git push --force --all origin" >&2
+fi
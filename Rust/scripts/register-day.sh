@@ -0,0 +1,60 @@
+#!/usr/bin/env sh
+# Registers a generated day with the `aoc` runner and lists it in the README.
+#
+# Usage: scripts/register-day.sh <day> <title>
+set -eu
+
+if [ $# -ne 2 ]; then
+    echo "usage: $0 <day> <title>" >&2
+    exit 1
+fi
+
+day=$1
+title=$2
+name=$(printf 'day-%02d' "$day")
+crate=$(printf 'day_%02d' "$day")
+root=$(cd "$(dirname "$0")/.." && pwd)
+
+# Appends `line` after the last line of `file` matching `pattern`, unless
+# `file` already contains `line`.
+insert_after_last() {
+    file=$1
+    pattern=$2
+    line=$3
+
+    if grep -qF -- "$line" "$file"; then
+        return
+    fi
+
+    last=$(grep -n -- "$pattern" "$file" | tail -n 1 | cut -d: -f1)
+    if [ -z "$last" ]; then
+        echo "no line matching '$pattern' in $file" >&2
+        exit 1
+    fi
+
+    awk -v last="$last" -v line="$line" '{ print } NR == last { print line }' "$file" > "$file.tmp"
+    mv "$file.tmp" "$file"
+}
+
+insert_after_last "$root/aoc/Cargo.toml" '^day-[0-9]* = ' \
+    "$name = { path = \"../$name\" }"
+insert_after_last "$root/aoc/src/days.rs" 'Puzzle::new::<day_' \
+    "    Puzzle::new::<$crate::Solution>(),"
+insert_after_last "$root/../README.md" '^- Day [0-9]*: ' \
+    "- Day $day: $title - [Rust](./Rust/$name/)"
+
+# A fuzz target for parsing the day's input and one for solving it.
+insert_after_last "$root/fuzz/Cargo.toml" '^day-[0-9]* = ' \
+    "$name = { path = \"../$name\" }"
+for kind in parse solve; do
+    target="${kind}_$crate"
+    file="$root/fuzz/fuzz_targets/$target.rs"
+    if [ -e "$file" ]; then
+        continue
+    fi
+
+    printf '#![no_main]\n\nlibfuzzer_sys::fuzz_target!(|input: &str| aoc_fuzz::%s::<%s::Solution>(input));\n' \
+        "$kind" "$crate" > "$file"
+    printf '\n[[bin]]\nname = "%s"\npath = "fuzz_targets/%s.rs"\ntest = false\ndoc = false\nbench = false\n' \
+        "$target" "$target" >> "$root/fuzz/Cargo.toml"
+done
@@ -0,0 +1,11 @@
+use aoc_core::Unimplemented;
+use aoc_parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum SolveError {
+    #[error("invalid input at {0}")]
+    Parse(#[from] ParseError),
+    #[error("{0}")]
+    Unimplemented(#[from] Unimplemented),
+}
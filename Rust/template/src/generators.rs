@@ -0,0 +1,15 @@
+use rand::{Rng, RngCore};
+
+/// A random input with `size` lines of up to five numbers each.
+pub fn generate(rng: &mut dyn RngCore, size: usize) -> String {
+    let lines: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let numbers: Vec<String> = (0..rng.gen_range(1..=5))
+                .map(|_| rng.gen_range(0..100).to_string())
+                .collect();
+            numbers.join(" ")
+        })
+        .collect();
+
+    lines.join("\n")
+}
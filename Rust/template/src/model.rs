@@ -0,0 +1,5 @@
+/// The parsed puzzle input, shared by both parts.
+#[derive(Debug, PartialEq)]
+pub struct Model {
+    pub lines: Vec<Vec<u32>>,
+}
@@ -0,0 +1,38 @@
+use aoc_parse::{finish, lines, numbers, IResult};
+use nom::combinator::map;
+
+use crate::model::Model;
+use crate::SolveError;
+
+pub fn parse_input(input: &str) -> Result<Model, SolveError> {
+    Ok(finish(input, parse_model)?)
+}
+
+fn parse_model(input: &str) -> IResult<'_, Model> {
+    map(lines(numbers), |lines| Model { lines })(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use rstest::rstest;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../examples/1.txt");
+
+    #[test]
+    fn parse_input_with_example() {
+        let result = parse_input(EXAMPLE);
+        assert!(result.is_ok(), "{:?}", result);
+    }
+
+    #[rstest]
+    fn parse_input_matches_snapshot(#[files("examples/*.txt")] path: PathBuf) {
+        let input = fs::read_to_string(&path).unwrap();
+        let name = path.file_stem().unwrap().to_string_lossy();
+        insta::assert_debug_snapshot!(format!("example_{}", name), parse_input(&input).unwrap());
+    }
+}
@@ -1,3 +1,8 @@
-pub fn process(_input: &str) -> String {
-    todo!("part 1 not implemented yet")
+use aoc_core::{Answer, Unimplemented};
+
+use crate::model::Model;
+use crate::SolveError;
+
+pub fn process(_model: &Model) -> Result<Answer, SolveError> {
+    Err(Unimplemented.into())
 }
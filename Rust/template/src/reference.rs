@@ -0,0 +1,35 @@
+use aoc_core::{Answer, Solver, Unimplemented};
+
+use crate::Solution;
+
+pub struct Reference;
+
+impl Solver for Reference {
+    const DAY: u8 = Solution::DAY;
+    const DIR: &'static str = Solution::DIR;
+    const INPUT: &'static str = Solution::INPUT;
+
+    type Input<'a> = &'a str;
+    type Error = Unimplemented;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Self::Error> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Err(Unimplemented)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<Answer, Self::Error> {
+        Err(Unimplemented)
+    }
+}
+
+aoc_core::example_tests!(crate::reference::Reference);
+
+aoc_core::generator_tests!(
+    crate::Solution,
+    crate::reference::Reference,
+    sizes: 1..50usize,
+    cases: 256,
+);